@@ -1,8 +1,25 @@
-use crate::models::{Game, Player, Rating, Tournament};
+use crate::models::{ConfidenceLevel, Game, Player, Rating};
 use anyhow::{Context, Result};
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use chrono::{DateTime, Utc};
+use sqlx::postgres::{PgPool, PgPoolOptions, PgRow};
+use sqlx::Row;
 use tracing::info;
 
+/// Page request for `Database::get_ratings`. Plain limit/offset rather than
+/// a keyset cursor: this crate has no API layer serving paged results yet,
+/// so there's nothing to keep a cursor token stable against.
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self { limit: 50, offset: 0 }
+    }
+}
+
 /// Database connection pool
 pub struct Database {
     pool: PgPool,
@@ -26,83 +43,218 @@ impl Database {
     pub async fn migrate(&self) -> Result<()> {
         info!("Running database migrations");
 
-        // TODO: Implement migrations using sqlx::migrate!()
-        // For now, this is scaffolding
+        sqlx::migrate!("./migrations")
+            .run(&self.pool)
+            .await
+            .context("Failed to run database migrations")?;
 
         Ok(())
     }
 
-    /// Insert or update a player
-    pub async fn upsert_player(&self, _player: &Player) -> Result<i64> {
-        // TODO: Implement with sqlx::query! after setting up database
-        // For now, this is scaffolding
-        Ok(0)
+    /// Insert or update a player, keyed on `cuescore_id`.
+    pub async fn upsert_player(&self, player: &Player) -> Result<i64> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO players (name, cuescore_id)
+            VALUES ($1, $2)
+            ON CONFLICT (cuescore_id) DO UPDATE SET name = excluded.name
+            RETURNING id
+            "#,
+        )
+        .bind(&player.name)
+        .bind(player.cuescore_id)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to upsert player")?;
+
+        row.try_get("id").context("Failed to read upserted player id")
     }
 
-    /// Insert a tournament
-    pub async fn insert_tournament(&self, _tournament: &Tournament) -> Result<i64> {
-        // TODO: Implement with sqlx::query! after setting up database
-        Ok(0)
+    /// Insert a tournament, keyed on its (already CueScore-assigned) id.
+    pub async fn insert_tournament(&self, tournament: &crate::models::Tournament) -> Result<i64> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO tournaments (id, name, venue_id, venue_name, start_date, end_date)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO UPDATE SET
+                name = excluded.name,
+                end_date = excluded.end_date
+            RETURNING id
+            "#,
+        )
+        .bind(tournament.id)
+        .bind(&tournament.name)
+        .bind(tournament.venue_id)
+        .bind(&tournament.venue_name)
+        .bind(tournament.start_date)
+        .bind(tournament.end_date)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to insert tournament")?;
+
+        row.try_get("id").context("Failed to read inserted tournament id")
     }
 
-    /// Insert a game
-    pub async fn insert_game(&self, _game: &Game) -> Result<i64> {
-        // TODO: Implement with sqlx::query! after setting up database
-        Ok(0)
+    /// Insert a game, upserting on `idx_games_dedup` so re-ingesting a
+    /// tournament updates scores in place instead of duplicating rows.
+    pub async fn insert_game(&self, game: &Game) -> Result<i64> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO games (tournament_id, player1_id, player2_id, player1_score, player2_score, date, weight)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tournament_id, player1_id, player2_id, date) DO UPDATE SET
+                player1_score = excluded.player1_score,
+                player2_score = excluded.player2_score,
+                weight = excluded.weight
+            RETURNING id
+            "#,
+        )
+        .bind(game.tournament_id)
+        .bind(game.player1_id)
+        .bind(game.player2_id)
+        .bind(game.player1_score)
+        .bind(game.player2_score)
+        .bind(game.date)
+        .bind(game.weight)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to insert game")?;
+
+        row.try_get("id").context("Failed to read inserted game id")
     }
 
     /// Get all games for rating calculation
     pub async fn get_all_games(&self) -> Result<Vec<Game>> {
-        // TODO: Implement with sqlx::query_as! after setting up database
-        Ok(Vec::new())
+        sqlx::query_as::<_, Game>(
+            r#"
+            SELECT id, tournament_id, player1_id, player2_id, player1_score, player2_score, date, weight
+            FROM games
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load games")
     }
 
-    /// Save player ratings
+    /// Save player ratings, overwriting each player's prior rating since
+    /// this schema keeps only the current one per player.
     pub async fn save_ratings(&self, ratings: &[Rating]) -> Result<()> {
         info!("Saving {} player ratings", ratings.len());
-        // TODO: Implement with sqlx::query! after setting up database
+
+        for rating in ratings {
+            sqlx::query(
+                r#"
+                INSERT INTO ratings (player_id, rating, games_played, confidence_level, calculated_at, rating_deviation, volatility)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (player_id) DO UPDATE SET
+                    rating = excluded.rating,
+                    games_played = excluded.games_played,
+                    confidence_level = excluded.confidence_level,
+                    calculated_at = excluded.calculated_at,
+                    rating_deviation = excluded.rating_deviation,
+                    volatility = excluded.volatility
+                "#,
+            )
+            .bind(rating.player_id)
+            .bind(rating.rating)
+            .bind(rating.games_played)
+            .bind(rating.confidence_level.as_str())
+            .bind(rating.calculated_at)
+            .bind(rating.rating_deviation)
+            .bind(rating.volatility)
+            .execute(&self.pool)
+            .await
+            .with_context(|| format!("Failed to save rating for player {}", rating.player_id))?;
+        }
+
         Ok(())
     }
-}
 
-// SQL migration scripts (to be used with sqlx-cli)
-pub const MIGRATIONS: &str = r#"
--- migrations/001_initial_schema.sql
-CREATE TABLE IF NOT EXISTS players (
-    id BIGSERIAL PRIMARY KEY,
-    name VARCHAR(255) NOT NULL,
-    cuescore_id BIGINT UNIQUE
-);
-
-CREATE TABLE IF NOT EXISTS tournaments (
-    id BIGINT PRIMARY KEY,
-    name VARCHAR(255) NOT NULL,
-    venue_id BIGINT NOT NULL,
-    venue_name VARCHAR(255) NOT NULL,
-    start_date TIMESTAMPTZ NOT NULL,
-    end_date TIMESTAMPTZ
-);
-
-CREATE TABLE IF NOT EXISTS games (
-    id BIGSERIAL PRIMARY KEY,
-    tournament_id BIGINT NOT NULL REFERENCES tournaments(id),
-    player1_id BIGINT NOT NULL REFERENCES players(id),
-    player2_id BIGINT NOT NULL REFERENCES players(id),
-    player1_score INTEGER NOT NULL,
-    player2_score INTEGER NOT NULL,
-    date TIMESTAMPTZ NOT NULL,
-    weight DOUBLE PRECISION NOT NULL DEFAULT 1.0
-);
-
-CREATE TABLE IF NOT EXISTS ratings (
-    player_id BIGINT PRIMARY KEY REFERENCES players(id),
-    rating DOUBLE PRECISION NOT NULL,
-    games_played INTEGER NOT NULL,
-    confidence_level VARCHAR(50) NOT NULL,
-    calculated_at TIMESTAMPTZ NOT NULL
-);
-
-CREATE INDEX IF NOT EXISTS idx_games_tournament ON games(tournament_id);
-CREATE INDEX IF NOT EXISTS idx_games_players ON games(player1_id, player2_id);
-CREATE INDEX IF NOT EXISTS idx_games_date ON games(date);
-"#;
+    /// Current ratings ordered highest-first, for a ranking listing.
+    pub async fn get_ratings(&self, pagination: Pagination) -> Result<Vec<Rating>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT player_id, rating, games_played, confidence_level, calculated_at, rating_deviation, volatility
+            FROM ratings
+            ORDER BY rating DESC
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(pagination.limit)
+        .bind(pagination.offset)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load ratings")?;
+
+        rows.iter().map(Self::row_to_rating).collect()
+    }
+
+    /// A single player's current rating, or `None` if they have no row in
+    /// `ratings` yet.
+    pub async fn get_player_rating(&self, player_id: i64) -> Result<Option<Rating>> {
+        let row = sqlx::query(
+            r#"
+            SELECT player_id, rating, games_played, confidence_level, calculated_at, rating_deviation, volatility
+            FROM ratings
+            WHERE player_id = $1
+            "#,
+        )
+        .bind(player_id)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to load player rating")?;
+
+        row.as_ref().map(Self::row_to_rating).transpose()
+    }
+
+    fn row_to_rating(row: &PgRow) -> Result<Rating> {
+        let confidence_level: String = row.try_get("confidence_level")?;
+        Ok(Rating {
+            player_id: row.try_get("player_id")?,
+            rating: row.try_get("rating")?,
+            games_played: row.try_get("games_played")?,
+            confidence_level: ConfidenceLevel::from_str(&confidence_level),
+            calculated_at: row.try_get("calculated_at")?,
+            rating_deviation: row.try_get("rating_deviation")?,
+            volatility: row.try_get("volatility")?,
+        })
+    }
+
+    /// Watermark for incremental sync: the timestamp last recorded by
+    /// [`Database::update_last_sync`] for a venue, or `None` if the venue has
+    /// never been synced (in which case callers should do a full pull).
+    pub async fn get_last_sync(&self, venue_id: i64) -> Result<Option<DateTime<Utc>>> {
+        let row = sqlx::query("SELECT last_sync FROM sync_metadata WHERE venue_id = $1")
+            .bind(venue_id)
+            .fetch_optional(&self.pool)
+            .await
+            .context("Failed to load sync watermark")?;
+
+        match row {
+            Some(row) => row
+                .try_get("last_sync")
+                .context("Failed to read sync watermark column"),
+            None => Ok(None),
+        }
+    }
+
+    /// Record that `venue_id` has been synced up through `synced_at`.
+    pub async fn update_last_sync(&self, venue_id: i64, synced_at: DateTime<Utc>) -> Result<()> {
+        info!("Updating sync watermark for venue {venue_id} to {synced_at}");
+
+        sqlx::query(
+            r#"
+            INSERT INTO sync_metadata (venue_id, last_sync)
+            VALUES ($1, $2)
+            ON CONFLICT (venue_id) DO UPDATE SET last_sync = excluded.last_sync
+            "#,
+        )
+        .bind(venue_id)
+        .bind(synced_at)
+        .execute(&self.pool)
+        .await
+        .context("Failed to update sync watermark")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use crate::models::Game;
+
+/// Glicko-2 scale factor converting this crate's 500-centered rating scale
+/// to Glicko-2's internal mu/phi scale.
+const GLICKO_SCALE: f64 = 173.7178;
+const DEFAULT_RATING: f64 = 500.0;
+pub(crate) const DEFAULT_RD: f64 = 350.0;
+pub(crate) const DEFAULT_VOLATILITY: f64 = 0.06;
+const TAU: f64 = 0.5;
+const CONVERGENCE_TOLERANCE: f64 = 0.000001;
+
+/// One opponent faced during the rating period, already converted to the
+/// Glicko-2 mu/phi scale, with the score fraction the player earned against
+/// them (1.0 win, 0.0 loss, 0.5 draw).
+struct Opponent {
+    mu: f64,
+    phi: f64,
+    score: f64,
+}
+
+/// Runs a Glicko-2 pass over one rating period's games (e.g. one scraped
+/// sync window) and returns each player's updated rating deviation and
+/// volatility, alongside Bradley-Terry's point-estimate rating.
+///
+/// This is self-contained: every player starts the period from the system
+/// defaults (r=500, RD=350, sigma=0.06) rather than a persisted prior
+/// period's state.
+pub fn calculate_deviations(games: &[Game]) -> HashMap<i64, (f64, f64)> {
+    let opponents_by_player = group_opponents_by_player(games);
+
+    opponents_by_player
+        .into_iter()
+        .map(|(player_id, opponents)| {
+            let (rd, volatility) = update_player(&opponents);
+            (player_id, (rd, volatility))
+        })
+        .collect()
+}
+
+fn group_opponents_by_player(games: &[Game]) -> HashMap<i64, Vec<Opponent>> {
+    let mut opponents_by_player: HashMap<i64, Vec<Opponent>> = HashMap::new();
+
+    for game in games {
+        let score_1 = match game.player1_score.cmp(&game.player2_score) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+            std::cmp::Ordering::Equal => 0.5,
+        };
+
+        opponents_by_player
+            .entry(game.player1_id)
+            .or_default()
+            .push(Opponent {
+                mu: 0.0,
+                phi: DEFAULT_RD / GLICKO_SCALE,
+                score: score_1,
+            });
+        opponents_by_player
+            .entry(game.player2_id)
+            .or_default()
+            .push(Opponent {
+                mu: 0.0,
+                phi: DEFAULT_RD / GLICKO_SCALE,
+                score: 1.0 - score_1,
+            });
+    }
+
+    opponents_by_player
+}
+
+/// Runs one player's Glicko-2 update and returns (RD', volatility').
+fn update_player(opponents: &[Opponent]) -> (f64, f64) {
+    if opponents.is_empty() {
+        return (DEFAULT_RD, DEFAULT_VOLATILITY);
+    }
+
+    let phi = DEFAULT_RD / GLICKO_SCALE;
+    let sigma = DEFAULT_VOLATILITY;
+    let mu = (DEFAULT_RATING - DEFAULT_RATING) / GLICKO_SCALE; // 0.0, kept for clarity
+
+    let v = variance(mu, opponents);
+    let delta = improvement(mu, opponents, v);
+
+    let new_sigma = solve_new_volatility(phi, sigma, v, delta);
+
+    let phi_star = (phi * phi + new_sigma * new_sigma).sqrt();
+    let new_phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+
+    let rd_prime = GLICKO_SCALE * new_phi;
+    (rd_prime, new_sigma)
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (std::f64::consts::PI * std::f64::consts::PI)).sqrt()
+}
+
+fn expected_score(mu: f64, opponent_mu: f64, opponent_phi: f64) -> f64 {
+    1.0 / (1.0 + (-g(opponent_phi) * (mu - opponent_mu)).exp())
+}
+
+fn variance(mu: f64, opponents: &[Opponent]) -> f64 {
+    let sum: f64 = opponents
+        .iter()
+        .map(|o| {
+            let e = expected_score(mu, o.mu, o.phi);
+            let g_phi = g(o.phi);
+            g_phi * g_phi * e * (1.0 - e)
+        })
+        .sum();
+
+    1.0 / sum
+}
+
+fn improvement(mu: f64, opponents: &[Opponent], v: f64) -> f64 {
+    let sum: f64 = opponents
+        .iter()
+        .map(|o| {
+            let e = expected_score(mu, o.mu, o.phi);
+            g(o.phi) * (o.score - e)
+        })
+        .sum();
+
+    v * sum
+}
+
+/// Finds sigma' via the Illinois (regula falsi) variant of the root-finding
+/// procedure from Glickman's Glicko-2 paper.
+fn solve_new_volatility(phi: f64, sigma: f64, v: f64, delta: f64) -> f64 {
+    let a = (sigma * sigma).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        let numerator = ex * (delta * delta - phi * phi - v - ex);
+        let denominator = 2.0 * (phi * phi + v + ex).powi(2);
+        numerator / denominator - (x - a) / (TAU * TAU)
+    };
+
+    let mut low = a;
+    let mut low_value = f(low);
+
+    let mut high = if delta * delta > phi * phi + v {
+        (delta * delta - phi * phi - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+    let mut high_value = f(high);
+
+    while (high - low).abs() > CONVERGENCE_TOLERANCE {
+        let new_point = low + (low - high) * low_value / (high_value - low_value);
+        let new_value = f(new_point);
+
+        if new_value * high_value <= 0.0 {
+            low = high;
+            low_value = high_value;
+        } else {
+            low_value /= 2.0;
+        }
+
+        high = new_point;
+        high_value = new_value;
+    }
+
+    (low / 2.0).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_deviation_shrinks_with_more_games() {
+        let games = vec![
+            Game {
+                id: 1,
+                tournament_id: 1,
+                player1_id: 1,
+                player2_id: 2,
+                player1_score: 5,
+                player2_score: 3,
+                date: Utc::now(),
+                weight: 1.0,
+            },
+            Game {
+                id: 2,
+                tournament_id: 1,
+                player1_id: 1,
+                player2_id: 3,
+                player1_score: 5,
+                player2_score: 1,
+                date: Utc::now(),
+                weight: 1.0,
+            },
+        ];
+
+        let deviations = calculate_deviations(&games);
+        let (rd, _volatility) = deviations[&1];
+
+        assert!(rd < DEFAULT_RD);
+    }
+}
@@ -1,14 +1,22 @@
 mod api;
 mod cache;
 mod db;
+mod glicko2;
 mod models;
+mod rate_limiter;
 mod rating;
 mod scraper;
 
 use anyhow::Result;
+use chrono::Utc;
+use db::Database;
+use scraper::VenueScraper;
 use tracing::{info, Level};
 use tracing_subscriber;
 
+/// Venues we track, in the same (id, display name) shape the scraper expects.
+const VENUES: &[(i64, &str)] = &[(12345, "147 Break Nowogrodzka")];
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize logging
@@ -19,12 +27,49 @@ async fn main() -> Result<()> {
     info!("Warsaw Pool Ranking - Rust Backend");
     info!("====================================");
 
-    // TODO: Main orchestration logic
-    // 1. Scrape venue pages to discover tournament IDs (scraper module)
-    // 2. Fetch tournament details from CueScore API (api module)
-    // 3. Cache the data (cache module)
-    // 4. Populate database (db module)
-    // 5. Calculate ratings (rating module)
+    let database_url = std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "postgres://localhost/warsaw_pool_ranking".to_string());
+    let db = Database::new(&database_url).await?;
+    db.migrate().await?;
+
+    let scraper = VenueScraper::new()?;
+
+    for &(venue_id, venue_name) in VENUES {
+        sync_venue(&db, &scraper, venue_id, venue_name).await?;
+    }
+
+    // TODO: Once the api module fetches full tournament details, expand each
+    // newly discovered tournament into ExpandedGames, populate the database,
+    // and recalculate ratings (rating module).
+
+    Ok(())
+}
+
+/// Discovers a venue's tournaments and advances its watermark to the current
+/// time. The stored watermark is only logged: discovery is not filtered by
+/// it, so every tournament listed for the venue is returned on each run.
+async fn sync_venue(
+    db: &Database,
+    scraper: &VenueScraper,
+    venue_id: i64,
+    venue_name: &str,
+) -> Result<()> {
+    let last_sync = db.get_last_sync(venue_id).await?;
+    match last_sync {
+        Some(watermark) => info!("Venue {venue_id}: syncing tournaments since {watermark}"),
+        None => info!("Venue {venue_id}: no watermark yet, doing a full pull"),
+    }
+
+    let tournament_ids = scraper
+        .scrape_venue_tournaments(venue_id, venue_name, None)
+        .await?;
+    info!("Venue {venue_id}: discovered {} tournament(s)", tournament_ids.len());
+
+    // TODO: fetch tournament details via the api module, filter to those with
+    // start_date > last_sync, and merge their ExpandedGames into the database
+    // instead of rebuilding it from scratch.
+
+    db.update_last_sync(venue_id, Utc::now()).await?;
 
     Ok(())
 }
@@ -21,7 +21,7 @@ pub struct Player {
 }
 
 /// Game/Match result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Game {
     pub id: i64,
     pub tournament_id: i64,
@@ -41,10 +41,16 @@ pub struct Rating {
     pub games_played: i32,
     pub confidence_level: ConfidenceLevel,
     pub calculated_at: DateTime<Utc>,
+    /// Glicko-2 rating deviation, on the same 500-centered scale as
+    /// `rating`. Lets callers show a confidence band (e.g. rating ± 2*RD).
+    pub rating_deviation: f64,
+    /// Glicko-2 volatility (sigma): how much the player's rating swings
+    /// from period to period.
+    pub volatility: f64,
 }
 
 /// Confidence level based on games played
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ConfidenceLevel {
     Unranked,      // < 10 games
     Provisional,   // 10-49 games
@@ -52,9 +58,57 @@ pub enum ConfidenceLevel {
     Established,   // 200+ games
 }
 
+impl ConfidenceLevel {
+    /// Stable string form stored in the `ratings.confidence_level` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfidenceLevel::Unranked => "unranked",
+            ConfidenceLevel::Provisional => "provisional",
+            ConfidenceLevel::Emerging => "emerging",
+            ConfidenceLevel::Established => "established",
+        }
+    }
+
+    /// Inverse of [`ConfidenceLevel::as_str`], for rows read back out of the
+    /// database. Falls back to `Unranked` for anything unrecognized rather
+    /// than failing the whole query.
+    pub fn from_str(raw: &str) -> Self {
+        match raw {
+            "provisional" => ConfidenceLevel::Provisional,
+            "emerging" => ConfidenceLevel::Emerging,
+            "established" => ConfidenceLevel::Established,
+            _ => ConfidenceLevel::Unranked,
+        }
+    }
+}
+
 /// Venue information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Venue {
     pub id: i64,
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confidence_level_round_trips_through_its_stored_string_form() {
+        let levels = [
+            ConfidenceLevel::Unranked,
+            ConfidenceLevel::Provisional,
+            ConfidenceLevel::Emerging,
+            ConfidenceLevel::Established,
+        ];
+
+        for level in levels {
+            assert_eq!(ConfidenceLevel::from_str(level.as_str()), level);
+        }
+    }
+
+    #[test]
+    fn confidence_level_from_str_falls_back_to_unranked_for_unrecognized_values() {
+        assert_eq!(ConfidenceLevel::from_str("garbage"), ConfidenceLevel::Unranked);
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use reqwest::{Response, StatusCode};
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Instant};
+use tracing::{debug, warn};
+
+/// Tunable knobs for polite scraping, surfaced so a deployment can back off
+/// harder (or go faster) without touching the scraper/api code.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimiterConfig {
+    pub requests_per_second: f64,
+    pub burst: usize,
+    pub max_retries: u32,
+}
+
+impl Default for RateLimiterConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 1.0,
+            burst: 1,
+            max_retries: 5,
+        }
+    }
+}
+
+/// A token-bucket limiter shared across every concurrent tournament fetch,
+/// so the overall request rate stays bounded no matter how much fan-out
+/// callers do.
+#[derive(Clone)]
+pub struct RateLimiter {
+    inner: Arc<Mutex<Bucket>>,
+    config: RateLimiterConfig,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimiterConfig) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Bucket {
+                tokens: config.burst as f64,
+                last_refill: Instant::now(),
+            })),
+            config,
+        }
+    }
+
+    /// Blocks until a token is available, consuming it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.inner.lock().await;
+                self.refill(&mut bucket);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - bucket.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.config.requests_per_second))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(delay) => sleep(delay).await,
+            }
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let elapsed = bucket.last_refill.elapsed().as_secs_f64();
+        let refilled = elapsed * self.config.requests_per_second;
+        bucket.tokens = (bucket.tokens + refilled).min(self.config.burst as f64);
+        bucket.last_refill = Instant::now();
+    }
+}
+
+/// Sends `request`, rate-limited by `limiter`, retrying on 429/5xx with
+/// exponential backoff. Honors a `Retry-After` header (seconds) when the
+/// server sends one instead of guessing.
+pub async fn send_with_retry(
+    limiter: &RateLimiter,
+    config: &RateLimiterConfig,
+    request: impl Fn() -> reqwest::RequestBuilder,
+) -> Result<Response> {
+    let mut attempt = 0;
+
+    loop {
+        limiter.acquire().await;
+        let response = request().send().await?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        if !is_retryable(response.status()) || attempt >= config.max_retries {
+            return Err(anyhow!("HTTP error: {}", response.status()));
+        }
+
+        let delay = retry_delay(&response, attempt);
+        warn!(
+            "Retrying after {:?} (attempt {}/{}), status {}",
+            delay,
+            attempt + 1,
+            config.max_retries,
+            response.status()
+        );
+        sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn retry_delay(response: &Response, attempt: u32) -> Duration {
+    if let Some(seconds) = retry_after_seconds(response) {
+        return Duration::from_secs(seconds);
+    }
+    exponential_backoff(attempt)
+}
+
+fn retry_after_seconds(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+}
+
+fn exponential_backoff(attempt: u32) -> Duration {
+    let base_ms = 500u64;
+    let delay_ms = base_ms.saturating_mul(1u64 << attempt.min(10));
+    debug!("Backing off {}ms before retry", delay_ms);
+    Duration::from_millis(delay_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_doubles() {
+        assert_eq!(exponential_backoff(0), Duration::from_millis(500));
+        assert_eq!(exponential_backoff(1), Duration::from_millis(1000));
+        assert_eq!(exponential_backoff(2), Duration::from_millis(2000));
+    }
+
+    #[tokio::test]
+    async fn test_limiter_allows_burst_without_waiting() {
+        let limiter = RateLimiter::new(RateLimiterConfig {
+            requests_per_second: 1000.0,
+            burst: 2,
+            max_retries: 1,
+        });
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::glicko2;
 use crate::models::{ConfidenceLevel, Game, Rating};
 use anyhow::Result;
 use chrono::Utc;
@@ -52,12 +53,20 @@ impl RatingCalculator {
         // Run MM (Minorization-Maximization) algorithm
         let log_ratings = self.mm_algorithm(&comparison_matrix, &wins, n_players);
 
+        // Glicko-2 pass over the same rating period, for a per-player
+        // uncertainty measure (RD/volatility) alongside the point estimate
+        let deviations = glicko2::calculate_deviations(games);
+
         // Convert log ratings to actual ratings and create Rating structs
         let mut ratings = Vec::new();
         for (idx, &player_id) in player_ids.iter().enumerate() {
             let log_rating = log_ratings[idx];
             let rating_value = log_rating.exp() * STARTER_RATING;
             let games_played = *games_count.get(&player_id).unwrap_or(&0);
+            let (rating_deviation, volatility) = deviations
+                .get(&player_id)
+                .copied()
+                .unwrap_or((glicko2::DEFAULT_RD, glicko2::DEFAULT_VOLATILITY));
 
             ratings.push(Rating {
                 player_id,
@@ -65,6 +74,8 @@ impl RatingCalculator {
                 games_played,
                 confidence_level: Self::get_confidence_level(games_played),
                 calculated_at: Utc::now(),
+                rating_deviation,
+                volatility,
             });
         }
 
@@ -4,22 +4,29 @@ use reqwest::Client;
 use scraper::{Html, Selector};
 use std::collections::HashSet;
 use std::time::Duration;
-use tokio::time::sleep;
 use tracing::{debug, info, warn};
 
+use crate::rate_limiter::{send_with_retry, RateLimiter, RateLimiterConfig};
+
 const BASE_URL: &str = "https://cuescore.com";
-const RATE_LIMIT_DELAY_MS: u64 = 1000; // 1 request per second
 
 /// Web scraper for CueScore venue pages
 pub struct VenueScraper {
     client: Client,
-    rate_limit_delay: Duration,
+    limiter: RateLimiter,
+    limiter_config: RateLimiterConfig,
     tournament_id_regex: Regex,
 }
 
 impl VenueScraper {
-    /// Create a new venue scraper
+    /// Create a new venue scraper with the default rate-limiter config
     pub fn new() -> Result<Self> {
+        Self::with_rate_limiter_config(RateLimiterConfig::default())
+    }
+
+    /// Create a new venue scraper, tuning the shared rate limiter's
+    /// requests-per-second budget, burst size and max retries.
+    pub fn with_rate_limiter_config(limiter_config: RateLimiterConfig) -> Result<Self> {
         let client = Client::builder()
             .user_agent("WarsawPoolRankings/2.0")
             .timeout(Duration::from_secs(30))
@@ -31,7 +38,8 @@ impl VenueScraper {
 
         Ok(Self {
             client,
-            rate_limit_delay: Duration::from_millis(RATE_LIMIT_DELAY_MS),
+            limiter: RateLimiter::new(limiter_config),
+            limiter_config,
             tournament_id_regex,
         })
     }
@@ -87,12 +95,7 @@ impl VenueScraper {
 
             info!("Scraping page {}: {}", page_num, url);
 
-            // Rate limiting
-            if page_num > 1 {
-                sleep(self.rate_limit_delay).await;
-            }
-
-            // Fetch page
+            // Fetch page (rate-limited and retried inside fetch_page)
             let html = match self.fetch_page(&url).await {
                 Ok(html) => html,
                 Err(e) => {
@@ -135,20 +138,15 @@ impl VenueScraper {
         Ok(tournament_ids)
     }
 
-    /// Fetch and parse an HTML page
+    /// Fetch and parse an HTML page, rate-limited and retried on 429/5xx
     async fn fetch_page(&self, url: &str) -> Result<Html> {
         debug!("Fetching page: {}", url);
 
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .context("Failed to send HTTP request")?;
-
-        if !response.status().is_success() {
-            anyhow::bail!("HTTP error: {}", response.status());
-        }
+        let response = send_with_retry(&self.limiter, &self.limiter_config, || {
+            self.client.get(url)
+        })
+        .await
+        .context("Failed to fetch page")?;
 
         let html_text = response
             .text()
@@ -24,11 +24,21 @@ impl CueScoreClient {
         Ok(Self { client })
     }
 
-    /// Fetch all tournaments for a venue
+    /// Fetch all tournaments for a venue, paging through the results.
     pub async fn fetch_venue_tournaments(&mut self, venue_id: i64) -> Result<Vec<Tournament>> {
         info!("Fetching tournaments for venue {}", venue_id);
 
-        let config = PaginationConfig::new();
+        let tournaments = self.fetch_paged(venue_id, PaginationConfig::new()).await?;
+
+        info!(
+            "Fetched {} tournaments for venue {}",
+            tournaments.len(),
+            venue_id
+        );
+        Ok(tournaments)
+    }
+
+    async fn fetch_paged(&mut self, venue_id: i64, config: PaginationConfig) -> Result<Vec<Tournament>> {
         let mut pages = PageIterator::new(config);
         let tournaments = Vec::new();
 
@@ -57,11 +67,6 @@ impl CueScoreClient {
             pages.advance();
         }
 
-        info!(
-            "Fetched {} tournaments for venue {}",
-            tournaments.len(),
-            venue_id
-        );
         Ok(tournaments)
     }
 
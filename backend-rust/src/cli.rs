@@ -0,0 +1,47 @@
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "warsaw-pool-rating backend")]
+pub struct Cli {
+    /// Command
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+#[clap(rename_all = "lower_case")]
+pub enum Command {
+    /// Start the backend server
+    Serve {
+        /// Port number (optional, defaults to 3000)
+        #[arg(short, long, default_value_t = 3000)]
+        port: u16,
+    },
+    /// Fetch new data from CueScore and store it in cache and database
+    Ingest {
+        /// Ignore stored sync state and re-pull every venue from scratch
+        #[arg(long)]
+        full: bool,
+    },
+    /// Calculate ratings based on data in the database
+    Process,
+    /// Forecast a single game between two players using the latest ratings
+    Predict {
+        /// Database player id of the first player
+        player_a: i32,
+        /// Database player id of the second player
+        player_b: i32,
+    },
+    /// Generate a single-elimination bracket seeded from the top N players
+    Seed {
+        /// Number of players to seed into the bracket
+        size: usize,
+    },
+    /// Print every game two players have played against each other
+    History {
+        /// CueScore id of the first player
+        player_a: i64,
+        /// CueScore id of the second player
+        player_b: i64,
+    },
+}
@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use chrono::NaiveDateTime;
-use rusqlite::params;
+use rusqlite::{params, OptionalExtension};
 
 use super::connection::DbConn;
 use super::models::Game;
@@ -15,7 +15,15 @@ pub fn insert_game(
     date: NaiveDateTime,
     weight: f64,
 ) -> Result<Game> {
-    let sql = "INSERT INTO games (tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) RETURNING id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at";
+    // Re-processing the same tournament (e.g. after an incremental ingest)
+    // must not duplicate games, so this upserts on the natural key instead
+    // of inserting blindly.
+    let sql = "INSERT INTO games (tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        ON CONFLICT(tournament_id, first_player_id, second_player_id, date) DO UPDATE SET
+            first_player_score = excluded.first_player_score,
+            second_player_score = excluded.second_player_score,
+            weight = excluded.weight
+        RETURNING id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at";
 
     conn.query_row(
         sql,
@@ -71,3 +79,101 @@ pub fn list_by_tournament(
 
     Ok(rows)
 }
+
+/// All games (each row is one game won) between the two given players,
+/// regardless of which side of the row they were stored on.
+pub fn find_games_between(
+    conn: &mut DbConn,
+    player_a_id: i32,
+    player_b_id: i32,
+) -> Result<Vec<Game>> {
+    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at FROM games WHERE (first_player_id = ?1 AND second_player_id = ?2) OR (first_player_id = ?2 AND second_player_id = ?1)";
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map(params![player_a_id, player_b_id], parse_game_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(rows)
+}
+
+/// Aggregate head-to-head record between two players, derived from the
+/// per-game-won rows returned by [`find_games_between`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadToHeadStats {
+    pub player_a_wins: i32,
+    pub player_b_wins: i32,
+    pub total_games: i32,
+    pub avg_score_margin: f64,
+    pub player_a_weighted_win_rate: f64,
+}
+
+pub fn head_to_head_stats(
+    conn: &mut DbConn,
+    player_a_id: i32,
+    player_b_id: i32,
+) -> Result<HeadToHeadStats> {
+    let games = find_games_between(conn, player_a_id, player_b_id)?;
+    Ok(summarize_head_to_head(&games, player_a_id))
+}
+
+/// Name of the tournament a game was played in, for reports (e.g.
+/// `Command::History`) that want more than a bare `tournament_id`.
+pub fn get_tournament_name(conn: &mut DbConn, tournament_id: i32) -> Result<Option<String>> {
+    conn.query_row(
+        "SELECT name FROM tournaments WHERE id = ?1",
+        params![tournament_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to look up tournament name")
+}
+
+/// Resolves a CueScore id to our internal DB player id, for commands (e.g.
+/// `Command::History`) that take the id a user actually knows rather than
+/// our own auto-increment one.
+pub fn resolve_player_id(conn: &mut DbConn, cuescore_id: i64) -> Result<Option<i32>> {
+    conn.query_row(
+        "SELECT id FROM players WHERE cuescore_id = ?1",
+        params![cuescore_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to resolve player id")
+}
+
+fn summarize_head_to_head(games: &[Game], player_a_id: i32) -> HeadToHeadStats {
+    let total_games = games.len() as i32;
+    let player_a_wins = games.iter().filter(|g| g.first_player_id == player_a_id).count() as i32;
+    let player_b_wins = total_games - player_a_wins;
+
+    let total_margin: i32 = games
+        .iter()
+        .map(|g| (g.first_player_score - g.second_player_score).abs())
+        .sum();
+    let avg_score_margin = if total_games > 0 {
+        total_margin as f64 / total_games as f64
+    } else {
+        0.0
+    };
+
+    let total_weight: f64 = games.iter().map(|g| g.weight).sum();
+    let player_a_weighted_wins: f64 = games
+        .iter()
+        .filter(|g| g.first_player_id == player_a_id)
+        .map(|g| g.weight)
+        .sum();
+    let player_a_weighted_win_rate = if total_weight > 0.0 {
+        player_a_weighted_wins / total_weight
+    } else {
+        0.0
+    };
+
+    HeadToHeadStats {
+        player_a_wins,
+        player_b_wins,
+        total_games,
+        avg_score_margin,
+        player_a_weighted_win_rate,
+    }
+}
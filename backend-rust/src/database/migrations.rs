@@ -0,0 +1,120 @@
+use anyhow::{Context, Result};
+use rusqlite::params;
+
+use super::connection::DbConn;
+
+/// One forward-only schema change, applied in its own transaction and
+/// recorded in `schema_version` so it never runs twice.
+struct Migration {
+    version: i32,
+    description: &'static str,
+    sql: &'static str,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "baseline schema",
+        sql: include_str!("schema.sql"),
+    },
+    Migration {
+        version: 2,
+        description: "add sync_metadata for per-venue incremental ingest",
+        sql: "CREATE TABLE IF NOT EXISTS sync_metadata (
+            venue_id INTEGER PRIMARY KEY,
+            last_sync TEXT NOT NULL
+        ) STRICT;",
+    },
+    Migration {
+        version: 3,
+        description: "dedup key on games so re-ingesting a tournament upserts instead of duplicating",
+        sql: "CREATE UNIQUE INDEX IF NOT EXISTS idx_games_dedup
+              ON games (tournament_id, first_player_id, second_player_id, date);",
+    },
+    Migration {
+        version: 4,
+        description: "add rating_type so more than one rating algorithm can be stored per player",
+        sql: "ALTER TABLE ratings ADD COLUMN rating_type TEXT NOT NULL DEFAULT 'bradley_terry';",
+    },
+];
+
+/// Brings the schema up to date without touching existing data: applies
+/// every migration newer than the recorded `schema_version`, each in its own
+/// transaction. Replaces the old `reset_database`, which wiped the whole
+/// database on every run.
+pub fn migrate(conn: &mut DbConn) -> Result<()> {
+    conn.execute("PRAGMA foreign_keys = ON", [])
+        .context("Failed to enable foreign keys")?;
+
+    ensure_schema_version_table(conn)?;
+    let current = current_version(conn)?;
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        apply_migration(conn, migration)?;
+    }
+
+    Ok(())
+}
+
+/// Wipes every table this crate knows about and rebuilds the schema from
+/// scratch. Used only by the admin `reset_db` endpoint, which exists
+/// precisely to discard all data; ordinary startup and processing should go
+/// through [`migrate`] instead, which never drops anything.
+pub fn reset_database(conn: &mut DbConn) -> Result<()> {
+    conn.execute_batch(
+        "DROP TABLE IF EXISTS ratings;
+         DROP TABLE IF EXISTS games;
+         DROP TABLE IF EXISTS tournaments;
+         DROP TABLE IF EXISTS players;
+         DROP TABLE IF EXISTS sync_metadata;
+         DROP TABLE IF EXISTS schema_version;",
+    )
+    .context("Failed to drop existing tables")?;
+
+    migrate(conn)
+}
+
+fn ensure_schema_version_table(conn: &mut DbConn) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+        ) STRICT",
+        [],
+    )
+    .context("Failed to create schema_version table")?;
+
+    Ok(())
+}
+
+fn current_version(conn: &mut DbConn) -> Result<i32> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+        [],
+        |row| row.get(0),
+    )
+    .context("Failed to read schema_version")
+}
+
+fn apply_migration(conn: &mut DbConn, migration: &Migration) -> Result<()> {
+    let tx = conn
+        .transaction()
+        .context("Failed to start migration transaction")?;
+
+    tx.execute_batch(migration.sql).with_context(|| {
+        format!(
+            "Failed to apply migration {}: {}",
+            migration.version, migration.description
+        )
+    })?;
+    tx.execute(
+        "INSERT INTO schema_version (version) VALUES (?1)",
+        params![migration.version],
+    )
+    .with_context(|| format!("Failed to record migration {}", migration.version))?;
+
+    tx.commit().context("Failed to commit migration")?;
+    log::info!("Applied migration {}: {}", migration.version, migration.description);
+
+    Ok(())
+}
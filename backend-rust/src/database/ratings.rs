@@ -5,6 +5,7 @@ use rusqlite::{params, OptionalExtension};
 use super::connection::DbConn;
 use super::models::Rating;
 
+#[allow(clippy::too_many_arguments)]
 pub fn insert_rating(
     conn: &mut DbConn,
     player_id: i32,
@@ -12,12 +13,24 @@ pub fn insert_rating(
     games_played: i32,
     confidence_level: &str,
     calculated_at: NaiveDateTime,
+    rating_deviation: f64,
+    is_stale: bool,
+    rating_type: &str,
 ) -> Result<Rating> {
-    let sql = "INSERT INTO ratings (player_id, rating, games_played, confidence_level, calculated_at) VALUES (?1, ?2, ?3, ?4, ?5) RETURNING id, player_id, rating, games_played, confidence_level, calculated_at, created_at";
+    let sql = "INSERT INTO ratings (player_id, rating, games_played, confidence_level, calculated_at, rating_deviation, is_stale, rating_type) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) RETURNING id, player_id, rating, games_played, confidence_level, calculated_at, created_at, rating_deviation, is_stale, rating_type";
 
     conn.query_row(
         sql,
-        params![player_id, rating, games_played, confidence_level, calculated_at],
+        params![
+            player_id,
+            rating,
+            games_played,
+            confidence_level,
+            calculated_at,
+            rating_deviation,
+            is_stale,
+            rating_type
+        ],
         parse_rating_row,
     )
     .context("Failed to insert rating")
@@ -32,6 +45,9 @@ fn parse_rating_row(row: &rusqlite::Row) -> rusqlite::Result<Rating> {
         confidence_level: row.get(4)?,
         calculated_at: row.get(5)?,
         created_at: row.get(6)?,
+        rating_deviation: row.get(7)?,
+        is_stale: row.get(8)?,
+        rating_type: row.get(9)?,
     })
 }
 
@@ -49,6 +65,24 @@ pub fn list_by_player(
     Ok(rows)
 }
 
+/// The most recent `rating_type` row for every player that has one, one row
+/// each. Scoped to a single `rating_type` so a player with both a
+/// `bradley_terry` and an `advantage_network` rating doesn't have one
+/// overwrite the other in the `MAX(id)` pick.
+pub fn list_latest(conn: &mut DbConn, rating_type: &str) -> Result<Vec<Rating>> {
+    let sql = "SELECT id, player_id, rating, games_played, confidence_level, calculated_at, created_at, rating_deviation, is_stale, rating_type
+        FROM ratings
+        WHERE rating_type = ?1
+          AND id IN (SELECT MAX(id) FROM ratings WHERE rating_type = ?1 GROUP BY player_id)";
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map(params![rating_type], parse_rating_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(rows)
+}
+
 pub fn get_latest_for_player(
     conn: &mut DbConn,
     player_id: i32,
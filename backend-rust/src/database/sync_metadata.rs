@@ -0,0 +1,30 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use rusqlite::{params, OptionalExtension};
+
+use super::connection::DbConn;
+
+/// Tracks the last time each venue was successfully synced, so a later
+/// `ingest` run can ask CueScore only for tournaments that started or
+/// finished since then instead of re-scraping the venue's full history.
+/// The table itself is created by [`super::migrations::migrate`].
+pub fn get_last_sync(conn: &mut DbConn, venue_id: i64) -> Result<Option<NaiveDateTime>> {
+    conn.query_row(
+        "SELECT last_sync FROM sync_metadata WHERE venue_id = ?1",
+        params![venue_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to read sync_metadata")
+}
+
+pub fn update_last_sync(conn: &mut DbConn, venue_id: i64, last_sync: NaiveDateTime) -> Result<()> {
+    conn.execute(
+        "INSERT INTO sync_metadata (venue_id, last_sync) VALUES (?1, ?2)
+         ON CONFLICT(venue_id) DO UPDATE SET last_sync = excluded.last_sync",
+        params![venue_id, last_sync],
+    )
+    .context("Failed to update sync_metadata")?;
+
+    Ok(())
+}
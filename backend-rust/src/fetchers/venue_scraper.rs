@@ -1,17 +1,28 @@
 use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDateTime};
 use log::info;
 use regex::Regex;
 use scraper::{Html, Selector};
 use std::collections::HashSet;
 
 use crate::http::RateLimitedClient;
-use crate::pagination::{PageIterator, PaginationConfig};
+use crate::pagination::{FetchStrategy, PageIterator, PaginationConfig};
 
 const BASE_URL: &str = "https://cuescore.com";
 const RATE_LIMIT_MS: u64 = 1000;
 const USER_AGENT: &str = "WarsawPoolRankings/2.0";
 const TIMEOUT_SECS: u64 = 30;
 
+/// Tournament listing page size on CueScore; a window returning at least
+/// this many ids may have more hiding behind it and needs bisecting further.
+const PAGE_SIZE_CAP: usize = 20;
+
+/// Below this span we stop bisecting and accept whatever a single fetch for
+/// the window returns, rather than recursing forever on a dense single day.
+fn min_bisect_window() -> Duration {
+    Duration::days(1)
+}
+
 /// Web scraper for discovering tournament IDs from CueScore venue pages
 pub struct VenueScraper {
     client: RateLimitedClient,
@@ -30,17 +41,49 @@ impl VenueScraper {
         })
     }
 
-    /// Scrape tournament IDs from a venue's tournament pages
+    /// Scrape tournament IDs from a venue's tournament pages, walking pages
+    /// one at a time.
     pub async fn scrape_venue_tournaments(
         &mut self,
         venue_id: i64,
         venue_name: &str,
         max_pages: Option<usize>,
+    ) -> Result<HashSet<i64>> {
+        let config = Self::build_pagination_config(max_pages);
+        self.scrape_venue_tournaments_with_config(venue_id, venue_name, config).await
+    }
+
+    /// Scrape tournament IDs from a venue's tournament pages, honoring
+    /// `config.strategy`. Large venues whose result set exceeds the
+    /// pagination cap should use `FetchStrategy::TimeWindowed` instead of
+    /// `Paged` so no tournaments are silently truncated.
+    pub async fn scrape_venue_tournaments_with_config(
+        &mut self,
+        venue_id: i64,
+        venue_name: &str,
+        config: PaginationConfig,
     ) -> Result<HashSet<i64>> {
         info!("Discovering tournaments from venue: {} (ID: {})", venue_name, venue_id);
 
-        let venue_name_encoded = urlencoding::encode(venue_name);
-        let config = Self::build_pagination_config(max_pages);
+        let venue_name_encoded = urlencoding::encode(venue_name).into_owned();
+
+        let all_ids = match config.strategy.clone() {
+            FetchStrategy::TimeWindowed { from, to } => {
+                self.fetch_ids_time_windowed(&venue_name_encoded, venue_id, from, to).await?
+            }
+            FetchStrategy::Paged => self.fetch_ids_paged(&venue_name_encoded, venue_id, config).await?,
+        };
+
+        info!("  → Found {} tournaments total", all_ids.len());
+        Ok(all_ids)
+    }
+
+    async fn fetch_ids_paged(
+        &mut self,
+        venue_name_encoded: &str,
+        venue_id: i64,
+        config: PaginationConfig,
+    ) -> Result<HashSet<i64>> {
         let mut pages = PageIterator::new(config);
         let mut all_ids = HashSet::new();
 
@@ -49,7 +92,7 @@ impl VenueScraper {
                 break;
             }
 
-            let url = Self::build_url(&venue_name_encoded, venue_id, pages.current_page());
+            let url = Self::build_url(venue_name_encoded, venue_id, pages.current_page());
             info!("  → Page {}...", pages.current_page());
 
             let html = match self.fetch_page(&url).await {
@@ -71,10 +114,43 @@ impl VenueScraper {
             pages.advance();
         }
 
-        info!("  → Found {} tournaments total", all_ids.len());
         Ok(all_ids)
     }
 
+    /// Fetch ids for `[from, to]` in one call; if the result looks capped
+    /// (at least `PAGE_SIZE_CAP` ids), bisect the window in half and union
+    /// the two halves, recursing down to `min_bisect_window()`.
+    fn fetch_ids_time_windowed<'a>(
+        &'a mut self,
+        venue_name_encoded: &'a str,
+        venue_id: i64,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<HashSet<i64>>> + 'a>> {
+        Box::pin(async move {
+            let url = Self::build_windowed_url(venue_name_encoded, venue_id, from, to);
+            info!("  → Window {} .. {}...", from, to);
+
+            let html = match self.fetch_page(&url).await {
+                Ok(html) => html,
+                Err(_) => return Ok(HashSet::new()),
+            };
+
+            let ids: HashSet<i64> = self.extract_ids(&html).into_iter().collect();
+
+            if ids.len() < PAGE_SIZE_CAP || to - from < min_bisect_window() {
+                return Ok(ids);
+            }
+
+            let mid = from + (to - from) / 2;
+            let mut merged = self.fetch_ids_time_windowed(venue_name_encoded, venue_id, from, mid).await?;
+            let right = self.fetch_ids_time_windowed(venue_name_encoded, venue_id, mid + Duration::seconds(1), to).await?;
+            merged.extend(right);
+
+            Ok(merged)
+        })
+    }
+
     // --- Construction Helpers ---
 
     fn compile_regex() -> Result<Regex> {
@@ -99,6 +175,17 @@ impl VenueScraper {
         crate::pagination::build_paginated_url_with_params(&base, page)
     }
 
+    fn build_windowed_url(venue_name: &str, venue_id: i64, from: NaiveDateTime, to: NaiveDateTime) -> String {
+        format!(
+            "{}/venue/{}/{}/tournaments?from={}&to={}",
+            BASE_URL,
+            venue_name,
+            venue_id,
+            from.and_utc().timestamp(),
+            to.and_utc().timestamp(),
+        )
+    }
+
     // --- Pagination Logic ---
 
     fn has_next_page(html: &Html) -> bool {
@@ -10,6 +10,7 @@ pub mod http;
 pub mod pagination;
 pub mod rate_limiter;
 pub mod rating;
+pub mod server;
 
 use std::collections::HashSet;
 
@@ -20,24 +21,36 @@ use cli::Cli;
 use crate::api::CueScoreClient;
 use crate::cache::Cache;
 use crate::cli::Command;
+use crate::config::VenueConfig;
 use crate::domain::{FetchProgress, TournamentCollection};
 use crate::fetchers::VenueScraper;
+use crate::pagination::PaginationConfig;
 
 pub fn interpret() -> Command {
     let cli = Cli::parse();
     cli.command
 }
 
-pub fn handle_serve(_port: u16) -> Result<()> {
-    todo!()
+pub async fn handle_serve(port: u16) -> Result<()> {
+    use std::sync::Arc;
+
+    let pool = database::create_pool()?;
+    let state = Arc::new(server::AppState { pool });
+    let app = server::create_router(state);
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    log::info!("Serving rankings on port {port}");
+    axum::serve(listener, app).await?;
+
+    Ok(())
 }
 
-pub fn handle_ingest() -> Result<()> {
+pub fn handle_ingest(full: bool) -> Result<()> {
     let runtime = tokio::runtime::Runtime::new()?;
-    runtime.block_on(ingest_data())
+    runtime.block_on(ingest_data(full))
 }
 
-async fn ingest_data() -> Result<()> {
+async fn ingest_data(full: bool) -> Result<()> {
     use log::info;
 
     info!("=== Starting Data Ingestion ===\n");
@@ -46,8 +59,16 @@ async fn ingest_data() -> Result<()> {
     let mut scraper = VenueScraper::new()?;
     let mut api_client = CueScoreClient::new()?;
 
+    let db_path = std::env::var("DATABASE_PATH")
+        .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
+    let pool = database::create_pool(&db_path)?;
+    let mut conn = database::get_connection(&pool)?;
+    database::migrations::migrate(&mut conn)?;
+
+    let venues = config::get_venues();
+
     // Step 1: Discover tournaments
-    let tournament_ids = discover_tournaments(&mut scraper).await?;
+    let tournament_ids = discover_tournaments(&mut scraper, &mut conn, &venues, full).await?;
     info!("  → Found {} unique tournaments\n", tournament_ids.len());
 
     // Step 2: Fetch tournament data
@@ -58,21 +79,46 @@ async fn ingest_data() -> Result<()> {
     save_parsed_cache(&cache, collection)?;
     info!("  → Saved to parsed cache\n");
 
+    // Step 4: Record how far we got, so the next non-`--full` run only asks
+    // each venue for what's new since now.
+    let synced_at = chrono::Utc::now().naive_utc();
+    for venue in &venues {
+        database::sync_metadata::update_last_sync(&mut conn, venue.id, synced_at)?;
+    }
+
     info!("=== Ingestion Complete ===");
     Ok(())
 }
 
-async fn discover_tournaments(scraper: &mut VenueScraper) -> Result<HashSet<i64>> {
+async fn discover_tournaments(
+    scraper: &mut VenueScraper,
+    conn: &mut database::DbConn,
+    venues: &[VenueConfig],
+    full: bool,
+) -> Result<HashSet<i64>> {
     use log::info;
-    use crate::config::get_venues;
 
     info!("Step 1: Discovering tournaments from venues...");
 
-    let venues = get_venues();
     let mut all_ids = HashSet::new();
 
     for venue in venues {
-        let ids = scraper.scrape_venue_tournaments(venue.id, venue.name, None).await?;
+        let since = if full {
+            None
+        } else {
+            database::sync_metadata::get_last_sync(conn, venue.id)?
+        };
+
+        let ids = match since {
+            Some(last_sync) => {
+                let config = PaginationConfig::new()
+                    .with_time_window(last_sync, chrono::Utc::now().naive_utc());
+                scraper
+                    .scrape_venue_tournaments_with_config(venue.id, venue.name, config)
+                    .await?
+            }
+            None => scraper.scrape_venue_tournaments(venue.id, venue.name, None).await?,
+        };
         all_ids.extend(ids);
     }
 
@@ -147,9 +193,9 @@ pub fn handle_process() -> Result<()> {
     let pool = database::create_pool(&db_path)?;
     let mut conn = database::get_connection(&pool)?;
 
-    // Step 1: Reset database (PoC - no migrations)
-    database::setup::reset_database(&mut conn)?;
-    info!("  → Database schema reset\n");
+    // Step 1: Bring the schema up to date without wiping existing data.
+    database::migrations::migrate(&mut conn)?;
+    info!("  → Database schema migrated\n");
 
     // Step 2: Load cached tournaments
     let tournaments = load_tournaments_from_cache(&cache)?;
@@ -163,14 +209,132 @@ pub fn handle_process() -> Result<()> {
     let ratings = calculate_player_ratings(&expanded_games)?;
     info!("  → Calculated ratings for {} players\n", ratings.len());
 
+    let advantage_network_ratings = calculate_advantage_network_player_ratings(&expanded_games, &ratings);
+    info!("  → Calculated advantage-network ratings for {} players\n", advantage_network_ratings.len());
+
     // Step 5: Save ratings to database
-    save_ratings_to_db(&mut conn, &ratings)?;
+    save_ratings_to_db(&mut conn, &ratings, "bradley_terry")?;
+    save_ratings_to_db(&mut conn, &advantage_network_ratings, "advantage_network")?;
     info!("  → Saved ratings to database\n");
 
     info!("=== Processing Complete ===");
     Ok(())
 }
 
+pub fn handle_predict(player_a: i32, player_b: i32) -> Result<()> {
+    use std::collections::HashMap;
+
+    let db_path = std::env::var("DATABASE_PATH")
+        .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
+    let pool = database::create_pool(&db_path)?;
+    let mut conn = database::get_connection(&pool)?;
+
+    let latest = database::ratings::list_latest(&mut conn, "bradley_terry")?;
+    let ratings: rating::types::RatingMap = latest
+        .iter()
+        .map(|r| (r.player_id, r.rating))
+        .collect::<HashMap<_, _>>();
+    let rating_deviations: HashMap<_, _> = latest
+        .into_iter()
+        .map(|r| (r.player_id, r.rating_deviation))
+        .collect();
+
+    let prediction = rating::predict_win_probability_with_confidence(
+        &ratings,
+        &rating_deviations,
+        player_a,
+        player_b,
+    )
+    .ok_or_else(|| anyhow::anyhow!("one or both players have no rating yet"))?;
+
+    println!("P(player {player_a} wins) = {:.4}", prediction.probability_a_wins);
+    println!("P(player {player_b} wins) = {:.4}", 1.0 - prediction.probability_a_wins);
+    if prediction.uncertain {
+        println!("(uncertain: one or both players have a high rating deviation)");
+    }
+    Ok(())
+}
+
+pub fn handle_seed(size: usize) -> Result<()> {
+    use std::collections::HashMap;
+
+    use rating::seeding::BracketSlot;
+
+    let db_path = std::env::var("DATABASE_PATH")
+        .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
+    let pool = database::create_pool(&db_path)?;
+    let mut conn = database::get_connection(&pool)?;
+
+    let mut latest = database::ratings::list_latest(&mut conn, "bradley_terry")?;
+    latest.sort_by(|a, b| b.rating.partial_cmp(&a.rating).unwrap());
+    latest.truncate(size);
+
+    let player_ids: Vec<rating::types::PlayerId> = latest.iter().map(|r| r.player_id).collect();
+    let ratings: rating::types::RatingMap =
+        latest.into_iter().map(|r| (r.player_id, r.rating)).collect::<HashMap<_, _>>();
+
+    let bracket = rating::generate_seeding(&player_ids, &ratings);
+
+    for (i, matchup) in bracket.iter().enumerate() {
+        let a = describe_slot(&matchup.player_a);
+        let b = describe_slot(&matchup.player_b);
+
+        match (&matchup.player_a, &matchup.player_b) {
+            (BracketSlot::Player(p1), BracketSlot::Player(p2)) => {
+                let p_a = rating::predict_win_probability(&ratings, *p1, *p2).unwrap_or(0.5);
+                println!("Match {}: {a} vs {b} (P({a} wins) = {:.4})", i + 1, p_a);
+            }
+            _ => println!("Match {}: {a} vs {b}", i + 1),
+        }
+    }
+
+    Ok(())
+}
+
+fn describe_slot(slot: &rating::seeding::BracketSlot) -> String {
+    use rating::seeding::BracketSlot;
+
+    match slot {
+        BracketSlot::Player(player_id) => format!("player {player_id}"),
+        BracketSlot::Bye => "BYE".to_string(),
+    }
+}
+
+pub fn handle_history(player_a_cuescore_id: i64, player_b_cuescore_id: i64) -> Result<()> {
+    let db_path = std::env::var("DATABASE_PATH")
+        .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
+    let pool = database::create_pool(&db_path)?;
+    let mut conn = database::get_connection(&pool)?;
+
+    let player_a_id = database::games::resolve_player_id(&mut conn, player_a_cuescore_id)?
+        .ok_or_else(|| anyhow::anyhow!("no player found for cuescore id {player_a_cuescore_id}"))?;
+    let player_b_id = database::games::resolve_player_id(&mut conn, player_b_cuescore_id)?
+        .ok_or_else(|| anyhow::anyhow!("no player found for cuescore id {player_b_cuescore_id}"))?;
+
+    let games = database::games::find_games_between(&mut conn, player_a_id, player_b_id)?;
+    if games.is_empty() {
+        println!("No games found between these players.");
+        return Ok(());
+    }
+
+    for game in &games {
+        let tournament_name = database::games::get_tournament_name(&mut conn, game.tournament_id)?
+            .unwrap_or_else(|| format!("tournament {}", game.tournament_id));
+        let winner_id = if game.first_player_score > game.second_player_score {
+            game.first_player_id
+        } else {
+            game.second_player_id
+        };
+
+        println!(
+            "{} | {} | winner: player {} | weight: {:.3}",
+            game.date, tournament_name, winner_id, game.weight
+        );
+    }
+
+    Ok(())
+}
+
 fn load_tournaments_from_cache(
     cache: &Cache,
 ) -> Result<Vec<crate::domain::TournamentResponse>> {
@@ -322,10 +486,63 @@ fn calculate_player_ratings(
     games: &[domain::ExpandedGame],
 ) -> Result<Vec<rating::PlayerRating>> {
     let game_results = convert_to_game_results(games);
-    let ratings = rating::calculate_ratings(&game_results);
+    let mut ratings = rating::calculate_ratings(&game_results);
+
+    let days_idle = days_since_last_played(games);
+    rating::apply_staleness(&mut ratings, &days_idle, &rating::staleness::StalenessConfig::default());
+
     Ok(ratings)
 }
 
+/// The advantage-network algorithm only produces a potential per player, not
+/// the games-played/confidence/staleness bookkeeping `PlayerRating` carries.
+/// Since it runs over the same games as `bradley_terry_ratings`, that
+/// bookkeeping is reused as-is and only the `rating` field is replaced.
+fn calculate_advantage_network_player_ratings(
+    games: &[domain::ExpandedGame],
+    bradley_terry_ratings: &[rating::PlayerRating],
+) -> Vec<rating::PlayerRating> {
+    let game_results = convert_to_game_results(games);
+    let mut advantage_ratings = rating::calculate_advantage_network_ratings(&game_results);
+
+    bradley_terry_ratings
+        .iter()
+        .map(|bt| rating::PlayerRating {
+            player_id: bt.player_id,
+            rating: advantage_ratings.remove(&bt.player_id).unwrap_or(bt.rating),
+            games_played: bt.games_played,
+            confidence_level: bt.confidence_level.clone(),
+            rating_deviation: bt.rating_deviation,
+            is_stale: bt.is_stale,
+        })
+        .collect()
+}
+
+/// Days between each player's most recent game (within `games`) and now,
+/// used to inflate rating deviation for players who've gone quiet.
+fn days_since_last_played(
+    games: &[domain::ExpandedGame],
+) -> std::collections::HashMap<rating::types::PlayerId, i64> {
+    use std::collections::HashMap;
+
+    let now = chrono::Utc::now().naive_utc();
+    let mut last_played: HashMap<rating::types::PlayerId, chrono::NaiveDateTime> = HashMap::new();
+
+    for game in games {
+        for player_id in [game.winner_id as i32, game.loser_id as i32] {
+            last_played
+                .entry(player_id)
+                .and_modify(|date| *date = (*date).max(game.date))
+                .or_insert(game.date);
+        }
+    }
+
+    last_played
+        .into_iter()
+        .map(|(player_id, date)| (player_id, now.signed_duration_since(date).num_days().max(0)))
+        .collect()
+}
+
 fn convert_to_game_results(
     games: &[domain::ExpandedGame],
 ) -> Vec<rating::GameResult> {
@@ -342,6 +559,7 @@ fn convert_to_game_results(
 fn save_ratings_to_db(
     conn: &mut database::DbConn,
     ratings: &[rating::PlayerRating],
+    rating_type: &str,
 ) -> Result<()> {
     let calculated_at = chrono::Utc::now().naive_utc();
 
@@ -358,6 +576,9 @@ fn save_ratings_to_db(
             player_rating.games_played,
             player_rating.confidence_level.as_str(),
             calculated_at,
+            player_rating.rating_deviation,
+            player_rating.is_stale,
+            rating_type,
         )?;
     }
 
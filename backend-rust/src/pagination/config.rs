@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+
+/// How a paginated endpoint should be walked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchStrategy {
+    /// Walk `?page=N` until the source signals no more pages, or `max_pages`
+    /// is hit. Breaks down once a venue has more results than the source
+    /// will paginate through.
+    Paged,
+    /// Split `[from, to]` into sub-ranges by recursive bisection instead,
+    /// so large venues are covered completely rather than truncated.
+    TimeWindowed { from: NaiveDateTime, to: NaiveDateTime },
+}
+
+#[derive(Debug, Clone)]
+pub struct PaginationConfig {
+    pub max_pages: Option<usize>,
+    pub strategy: FetchStrategy,
+}
+
+impl PaginationConfig {
+    pub fn new() -> Self {
+        Self {
+            max_pages: None,
+            strategy: FetchStrategy::Paged,
+        }
+    }
+
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    pub fn with_time_window(mut self, from: NaiveDateTime, to: NaiveDateTime) -> Self {
+        self.strategy = FetchStrategy::TimeWindowed { from, to };
+        self
+    }
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
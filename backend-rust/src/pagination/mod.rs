@@ -2,6 +2,6 @@ mod config;
 mod iterator;
 mod urls;
 
-pub use config::PaginationConfig;
+pub use config::{FetchStrategy, PaginationConfig};
 pub use iterator::PageIterator;
 pub use urls::{build_paginated_url, build_paginated_url_with_params};
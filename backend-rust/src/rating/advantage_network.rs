@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+
+use super::normalization::normalize_ratings;
+use super::types::{GameResult, PlayerId, RatingMap};
+
+/// Smoothing constant in `advantage_AB = ln((wins_AB + ALPHA) / (wins_BA + ALPHA))`,
+/// keeping the log-odds finite for a pair that has only ever gone one way.
+const ALPHA: f64 = 0.5;
+
+const CONVERGENCE_TOLERANCE: f64 = 1e-6;
+const MAX_ITERATIONS: usize = 1000;
+
+/// One undirected pair's empirical log-odds of `a` beating `b`, smoothed by
+/// `ALPHA`.
+struct AdvantageEdge {
+    a: PlayerId,
+    b: PlayerId,
+    advantage_ab: f64,
+}
+
+/// Graph-based alternative to the batch Bradley-Terry MLE: builds a weighted
+/// digraph of pairwise log-odds and solves for a per-player potential `φ`
+/// that best explains all of them, rather than fitting a single global
+/// multiplicative strength. Captures transitive strength (beating players
+/// who beat strong players) that a flat average misses.
+///
+/// Solves `minimize Σ (advantage_AB - (φ_A - φ_B))²` over edges via
+/// Gauss-Seidel-style iterative averaging (`φ_A ← mean over neighbors B of
+/// (φ_B + advantage_AB)`) instead of inverting the graph Laplacian directly,
+/// the same fixed-point style `bradley_terry::calculate_ratings` already
+/// uses for its MM iteration.
+pub fn calculate_ratings(games: &[GameResult]) -> RatingMap {
+    let edges = build_edges(games);
+    let neighbors = build_neighbor_index(&edges);
+    let mut potentials = initialize_potentials(&edges);
+
+    iterate_until_convergence(&mut potentials, &neighbors);
+    normalize_ratings(&mut potentials);
+
+    potentials
+}
+
+fn build_edges(games: &[GameResult]) -> Vec<AdvantageEdge> {
+    let mut wins: HashMap<(PlayerId, PlayerId), f64> = HashMap::new();
+
+    for game in games {
+        *wins.entry((game.winner_id, game.loser_id)).or_insert(0.0) += game.weight;
+    }
+
+    let mut pairs: HashSet<(PlayerId, PlayerId)> = HashSet::new();
+    for &(a, b) in wins.keys() {
+        pairs.insert(if a < b { (a, b) } else { (b, a) });
+    }
+
+    pairs
+        .into_iter()
+        .map(|(a, b)| {
+            let wins_ab = *wins.get(&(a, b)).unwrap_or(&0.0);
+            let wins_ba = *wins.get(&(b, a)).unwrap_or(&0.0);
+            AdvantageEdge {
+                a,
+                b,
+                advantage_ab: ((wins_ab + ALPHA) / (wins_ba + ALPHA)).ln(),
+            }
+        })
+        .collect()
+}
+
+fn initialize_potentials(edges: &[AdvantageEdge]) -> RatingMap {
+    let mut potentials = RatingMap::new();
+    for edge in edges {
+        potentials.entry(edge.a).or_insert(0.0);
+        potentials.entry(edge.b).or_insert(0.0);
+    }
+    potentials
+}
+
+/// For each player, every neighbor reachable by an edge, paired with the
+/// potential-difference that edge implies for this player (`advantage_AB` if
+/// this player is `A`, `-advantage_AB` if this player is `B`).
+fn build_neighbor_index(edges: &[AdvantageEdge]) -> HashMap<PlayerId, Vec<(PlayerId, f64)>> {
+    let mut neighbors: HashMap<PlayerId, Vec<(PlayerId, f64)>> = HashMap::new();
+
+    for edge in edges {
+        neighbors.entry(edge.a).or_default().push((edge.b, edge.advantage_ab));
+        neighbors.entry(edge.b).or_default().push((edge.a, -edge.advantage_ab));
+    }
+
+    neighbors
+}
+
+fn iterate_until_convergence(
+    potentials: &mut RatingMap,
+    neighbors: &HashMap<PlayerId, Vec<(PlayerId, f64)>>,
+) {
+    for _ in 0..MAX_ITERATIONS {
+        let mut max_delta: f64 = 0.0;
+
+        for (&player_id, incident) in neighbors {
+            let sum: f64 = incident.iter().map(|&(other, advantage)| potentials[&other] + advantage).sum();
+            let updated = sum / incident.len() as f64;
+            let previous = potentials.insert(player_id, updated).unwrap_or(0.0);
+            max_delta = max_delta.max((updated - previous).abs());
+        }
+
+        if max_delta < CONVERGENCE_TOLERANCE {
+            break;
+        }
+    }
+}
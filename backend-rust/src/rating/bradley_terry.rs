@@ -2,18 +2,72 @@ use std::collections::HashMap;
 
 use super::convergence::{has_converged, should_continue};
 use super::normalization::normalize_ratings;
+use super::staleness::DEFAULT_RATING_DEVIATION;
 use super::types::{GameResult, PlayerId, PlayerRating, RatingMap};
 
 const INITIAL_RATING: f64 = 1.0;
 
+/// `var_const` in `variance = var_const / information`: scales how strongly
+/// accumulated game information translates into a low rating deviation.
+/// Configurable per deployment; 1.0 keeps RD in the same ballpark as
+/// [`DEFAULT_RATING_DEVIATION`] for a typically-played player.
+const DEFAULT_VARIANCE_CONSTANT: f64 = 1.0;
+
 pub fn calculate_ratings(games: &[GameResult]) -> Vec<PlayerRating> {
     let mut ratings = initialize_ratings(games);
     let games_count = count_games_per_player(games);
 
     iterate_until_convergence(&mut ratings, games);
+
+    // RD is derived from the curvature of the log-likelihood at this fixed
+    // point, so it must be computed before `normalize_ratings` rescales the
+    // ratings onto the display scale.
+    let rating_deviations = calculate_rating_deviations(&ratings, games, DEFAULT_VARIANCE_CONSTANT);
+
     normalize_ratings(&mut ratings);
 
-    build_player_ratings(ratings, games_count)
+    build_player_ratings(ratings, games_count, &rating_deviations)
+}
+
+/// For each player, the rating deviation implied by the curvature of the
+/// ratio model's log-likelihood at the converged ratings: accumulate an
+/// "information" term of `weight / (r_i + r_j)^2` over their games, then set
+/// `rd = sqrt(var_const / information)`. Players with few or weakly-weighted
+/// games accumulate little information and get a large (uncertain) RD;
+/// heavily-played players get a small one.
+fn calculate_rating_deviations(
+    ratings: &RatingMap,
+    games: &[GameResult],
+    var_const: f64,
+) -> HashMap<PlayerId, f64> {
+    ratings
+        .keys()
+        .map(|&player_id| {
+            let information = sum_information(player_id, ratings, games);
+            let rd = if information > 0.0 {
+                (var_const / information).sqrt()
+            } else {
+                DEFAULT_RATING_DEVIATION
+            };
+            (player_id, rd)
+        })
+        .collect()
+}
+
+fn sum_information(player_id: PlayerId, ratings: &RatingMap, games: &[GameResult]) -> f64 {
+    games
+        .iter()
+        .filter(|g| involves_player(g, player_id))
+        .map(|g| information_contribution(g, player_id, ratings))
+        .sum()
+}
+
+fn information_contribution(game: &GameResult, player_id: PlayerId, ratings: &RatingMap) -> f64 {
+    let opponent_id = get_opponent_id(game, player_id);
+    let player_rating = get_rating(ratings, player_id);
+    let opponent_rating = get_rating(ratings, opponent_id);
+
+    game.weight / (player_rating + opponent_rating).powi(2)
 }
 
 fn initialize_ratings(games: &[GameResult]) -> RatingMap {
@@ -137,10 +191,11 @@ fn get_rating(ratings: &RatingMap, player_id: PlayerId) -> f64 {
 fn build_player_ratings(
     ratings: RatingMap,
     games_count: HashMap<PlayerId, i32>,
+    rating_deviations: &HashMap<PlayerId, f64>,
 ) -> Vec<PlayerRating> {
     ratings
         .into_iter()
-        .map(|(id, rating)| build_single_rating(id, rating, &games_count))
+        .map(|(id, rating)| build_single_rating(id, rating, &games_count, rating_deviations))
         .collect()
 }
 
@@ -148,16 +203,23 @@ fn build_single_rating(
     player_id: PlayerId,
     rating: f64,
     games_count: &HashMap<PlayerId, i32>,
+    rating_deviations: &HashMap<PlayerId, f64>,
 ) -> PlayerRating {
     use super::types::ConfidenceLevel;
 
     let games_played = games_count.get(&player_id).copied().unwrap_or(0);
-    let confidence_level = ConfidenceLevel::from_games_played(games_played);
+    let rating_deviation = rating_deviations
+        .get(&player_id)
+        .copied()
+        .unwrap_or(DEFAULT_RATING_DEVIATION);
+    let confidence_level = ConfidenceLevel::from_rating_deviation(rating_deviation);
 
     PlayerRating {
         player_id,
         rating,
         games_played,
         confidence_level,
+        rating_deviation,
+        is_stale: false,
     }
 }
@@ -1,9 +1,17 @@
+pub mod advantage_network;
 pub mod bradley_terry;
 mod convergence;
 mod normalization;
+pub mod prediction;
+pub mod seeding;
+pub mod staleness;
 pub mod types;
 pub mod weighting;
 
+pub use advantage_network::calculate_ratings as calculate_advantage_network_ratings;
 pub use bradley_terry::calculate_ratings;
+pub use prediction::{predict_match, predict_win_probability, predict_win_probability_with_confidence, WinProbability};
+pub use seeding::generate_seeding;
+pub use staleness::apply_staleness;
 pub use types::{ConfidenceLevel, GameResult, PlayerRating};
 pub use weighting::calculate_weight;
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::staleness::DEFAULT_RATING_DEVIATION;
+use super::types::{PlayerId, RatingMap};
+
+/// Probability that `player_a` beats `player_b` in a single game, under the
+/// Bradley-Terry model this crate already fits: P(a beats b) = r_a / (r_a + r_b).
+/// Returns `None` if either player has no rating.
+pub fn predict_win_probability(
+    ratings: &RatingMap,
+    player_a: PlayerId,
+    player_b: PlayerId,
+) -> Option<f64> {
+    let rating_a = *ratings.get(&player_a)?;
+    let rating_b = *ratings.get(&player_b)?;
+
+    Some(rating_a / (rating_a + rating_b))
+}
+
+/// Combined rating deviation (summed in quadrature across both players)
+/// above which a forecast is flagged `uncertain` rather than treated as a
+/// confident prediction.
+const UNCERTAIN_COMBINED_RD: f64 = 2.0 * DEFAULT_RATING_DEVIATION;
+
+/// A single-game forecast, plus whether either player's rating is uncertain
+/// enough that the probability shouldn't be taken at face value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WinProbability {
+    pub probability_a_wins: f64,
+    pub uncertain: bool,
+}
+
+/// Like [`predict_win_probability`], but also flags the forecast as
+/// `uncertain` using each player's rating deviation (see
+/// [`crate::rating::bradley_terry::calculate_ratings`]), so a matchup
+/// between two weakly-rated players doesn't read as more confident than it
+/// is. Returns `None` if either player has no rating.
+pub fn predict_win_probability_with_confidence(
+    ratings: &RatingMap,
+    rating_deviations: &HashMap<PlayerId, f64>,
+    player_a: PlayerId,
+    player_b: PlayerId,
+) -> Option<WinProbability> {
+    let probability_a_wins = predict_win_probability(ratings, player_a, player_b)?;
+
+    let rd_a = rating_deviations.get(&player_a).copied().unwrap_or(DEFAULT_RATING_DEVIATION);
+    let rd_b = rating_deviations.get(&player_b).copied().unwrap_or(DEFAULT_RATING_DEVIATION);
+    let combined_rd = (rd_a.powi(2) + rd_b.powi(2)).sqrt();
+
+    Some(WinProbability {
+        probability_a_wins,
+        uncertain: combined_rd > UNCERTAIN_COMBINED_RD,
+    })
+}
+
+/// Probability that `player_a` wins a race-to-`race_to` match against
+/// `player_b`, treating each game as an independent trial with the
+/// single-game win probability from [`predict_win_probability`].
+pub fn predict_match(
+    ratings: &RatingMap,
+    player_a: PlayerId,
+    player_b: PlayerId,
+    race_to: u32,
+) -> Option<f64> {
+    let p_a = predict_win_probability(ratings, player_a, player_b)?;
+
+    Some(match_win_probability(p_a, race_to))
+}
+
+/// Sums independent game outcomes to get the probability of winning a
+/// race-to-`race_to` match, via the negative-binomial recurrence:
+/// P(win | a_wins, b_wins) = p * P(win | a_wins+1, b_wins) + (1-p) * P(win | a_wins, b_wins+1).
+fn match_win_probability(p: f64, race_to: u32) -> f64 {
+    let race_to = race_to as usize;
+    let mut memo = vec![vec![None; race_to + 1]; race_to + 1];
+    win_probability_from(p, 0, 0, race_to, &mut memo)
+}
+
+fn win_probability_from(
+    p: f64,
+    a_wins: usize,
+    b_wins: usize,
+    race_to: usize,
+    memo: &mut Vec<Vec<Option<f64>>>,
+) -> f64 {
+    if a_wins == race_to {
+        return 1.0;
+    }
+    if b_wins == race_to {
+        return 0.0;
+    }
+    if let Some(cached) = memo[a_wins][b_wins] {
+        return cached;
+    }
+
+    let result = p * win_probability_from(p, a_wins + 1, b_wins, race_to, memo)
+        + (1.0 - p) * win_probability_from(p, a_wins, b_wins + 1, race_to, memo);
+
+    memo[a_wins][b_wins] = Some(result);
+    result
+}
@@ -0,0 +1,94 @@
+use super::types::{PlayerId, RatingMap};
+
+/// A single first-round bracket slot: either a player seed, or a bye when the
+/// field isn't a power of two.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BracketSlot {
+    Player(PlayerId),
+    Bye,
+}
+
+/// One first-round matchup in the generated bracket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matchup {
+    pub player_a: BracketSlot,
+    pub player_b: BracketSlot,
+}
+
+/// Builds a single-elimination bracket for `player_ids`, seeded by descending
+/// `ratings` so that the top two seeds can only meet in the final, the top
+/// four only in the semifinals, and so on. Byes go to the top seeds when the
+/// field isn't a power of two.
+pub fn generate_seeding(player_ids: &[PlayerId], ratings: &RatingMap) -> Vec<Matchup> {
+    let ranked = rank_players_by_rating(player_ids, ratings);
+    let bracket_size = next_power_of_two(ranked.len());
+    let seed_positions = fold_seed_positions(bracket_size);
+
+    let slots: Vec<BracketSlot> = seed_positions
+        .into_iter()
+        .map(|seed| slot_for_seed(seed, &ranked))
+        .collect();
+
+    pair_into_matchups(slots)
+}
+
+fn rank_players_by_rating(player_ids: &[PlayerId], ratings: &RatingMap) -> Vec<PlayerId> {
+    let mut ranked = player_ids.to_vec();
+    ranked.sort_by(|a, b| {
+        let rating_a = ratings.get(a).copied().unwrap_or(0.0);
+        let rating_b = ratings.get(b).copied().unwrap_or(0.0);
+        rating_b.partial_cmp(&rating_a).unwrap()
+    });
+    ranked
+}
+
+fn next_power_of_two(n: usize) -> usize {
+    if n <= 1 {
+        return 1;
+    }
+    let mut size = 1;
+    while size < n {
+        size *= 2;
+    }
+    size
+}
+
+fn slot_for_seed(seed: usize, ranked: &[PlayerId]) -> BracketSlot {
+    match ranked.get(seed - 1) {
+        Some(&player_id) => BracketSlot::Player(player_id),
+        None => BracketSlot::Bye,
+    }
+}
+
+fn pair_into_matchups(slots: Vec<BracketSlot>) -> Vec<Matchup> {
+    slots
+        .chunks(2)
+        .map(|pair| Matchup {
+            player_a: pair[0].clone(),
+            player_b: pair[1].clone(),
+        })
+        .collect()
+}
+
+/// Standard "fold" seed ordering for a bracket of size `bracket_size`
+/// (a power of two): `S_k = interleave(S_{k-1}, (2^k + 1) - S_{k-1})`,
+/// starting from `S_0 = [1]`, e.g. `[1, 4, 2, 3]` for a bracket of four.
+fn fold_seed_positions(bracket_size: usize) -> Vec<usize> {
+    let mut positions = vec![1];
+
+    while positions.len() < bracket_size {
+        let next_size = positions.len() * 2;
+        let mirrored: Vec<usize> = positions
+            .iter()
+            .map(|seed| next_size + 1 - seed)
+            .collect();
+
+        positions = interleave(&positions, &mirrored);
+    }
+
+    positions
+}
+
+fn interleave(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().zip(b.iter()).flat_map(|(&x, &y)| [x, y]).collect()
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use super::types::{PlayerId, PlayerRating};
+
+/// Rating deviation given to a player whose games carry no information to
+/// derive one from, before any inactivity inflation is applied.
+pub const DEFAULT_RATING_DEVIATION: f64 = 50.0;
+
+/// A rating deviation above this is flagged as stale.
+const STALE_THRESHOLD: f64 = 150.0;
+
+/// `c` in `phi <- min(phi_max, sqrt(phi^2 + c * days_idle))`: how fast
+/// uncertainty grows per day of inactivity. Configurable per deployment.
+#[derive(Debug, Clone, Copy)]
+pub struct StalenessConfig {
+    pub decay_constant: f64,
+    pub max_rating_deviation: f64,
+}
+
+impl Default for StalenessConfig {
+    fn default() -> Self {
+        Self {
+            decay_constant: 0.5,
+            max_rating_deviation: 350.0,
+        }
+    }
+}
+
+/// Inflates a player's rating deviation based on days since they last
+/// played, per `phi <- min(phi_max, sqrt(phi^2 + c * t))`.
+pub fn inflate_for_inactivity(rating_deviation: f64, days_idle: i64, config: &StalenessConfig) -> f64 {
+    let inflated = (rating_deviation.powi(2) + config.decay_constant * days_idle.max(0) as f64).sqrt();
+    inflated.min(config.max_rating_deviation)
+}
+
+/// Applies inactivity inflation to every rating, using each player's days
+/// since last played as supplied by the caller. Players missing from
+/// `days_idle` keep their deviation unchanged.
+pub fn apply_staleness(
+    ratings: &mut [PlayerRating],
+    days_idle: &HashMap<PlayerId, i64>,
+    config: &StalenessConfig,
+) {
+    for rating in ratings.iter_mut() {
+        if let Some(&days) = days_idle.get(&rating.player_id) {
+            rating.rating_deviation = inflate_for_inactivity(rating.rating_deviation, days, config);
+        }
+        rating.is_stale = rating.rating_deviation > STALE_THRESHOLD;
+    }
+}
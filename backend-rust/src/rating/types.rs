@@ -10,6 +10,15 @@ pub struct PlayerRating {
     pub rating: RatingValue,
     pub games_played: i32,
     pub confidence_level: ConfidenceLevel,
+    /// Rating deviation: how uncertain this rating is. Derived from the
+    /// information in the player's games (see
+    /// [`crate::rating::bradley_terry::calculate_ratings`]), falling back to
+    /// [`crate::rating::staleness::DEFAULT_RATING_DEVIATION`] when there is
+    /// none, and grows the longer a player goes without playing.
+    pub rating_deviation: f64,
+    /// Set once `rating_deviation` has grown past the staleness threshold,
+    /// so API consumers can flag the rating as no longer trustworthy.
+    pub is_stale: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,13 +29,19 @@ pub enum ConfidenceLevel {
 }
 
 impl ConfidenceLevel {
-    pub fn from_games_played(games: i32) -> Self {
-        if games < 10 {
-            ConfidenceLevel::Low
-        } else if games < 30 {
+    /// Buckets a computed rating deviation (see
+    /// [`crate::rating::bradley_terry::calculate_ratings`]) into a display
+    /// tier, replacing the old flat games-played thresholds with one that
+    /// also accounts for opponent strength and recency.
+    pub fn from_rating_deviation(rating_deviation: f64) -> Self {
+        use super::staleness::DEFAULT_RATING_DEVIATION;
+
+        if rating_deviation <= DEFAULT_RATING_DEVIATION {
+            ConfidenceLevel::High
+        } else if rating_deviation <= 2.0 * DEFAULT_RATING_DEVIATION {
             ConfidenceLevel::Medium
         } else {
-            ConfidenceLevel::High
+            ConfidenceLevel::Low
         }
     }
 
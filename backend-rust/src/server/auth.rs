@@ -0,0 +1,12 @@
+use axum::http::HeaderMap;
+
+/// Shared secret gating the mutating/admin routes. Ranking data stays
+/// read-only to anyone; only requests carrying this bearer token may
+/// trigger a resync or reset the database.
+const ADMIN_TOKEN: &str = "Bearer secret";
+
+/// Checked independently on every request to an admin route, rather than
+/// once at startup, so there's no stateful session to keep in sync.
+pub fn is_authorized(headers: &HeaderMap) -> bool {
+    headers.get("Authorization").and_then(|h| h.to_str().ok()) == Some(ADMIN_TOKEN)
+}
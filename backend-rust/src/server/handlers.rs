@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+
+use crate::database::{self, models::{Game, Rating}};
+use crate::rating::{self, types::{GameResult, RatingMap}};
+
+use super::{auth, AppState, SharedState};
+
+pub async fn list_players(State(state): State<SharedState>) -> impl IntoResponse {
+    let mut conn = state.pool.get_connection();
+    match database::ratings::list_latest(&mut conn, "bradley_terry") {
+        Ok(ratings) => Json(ratings).into_response(),
+        Err(err) => {
+            log::error!("Failed to list players: {err:?}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RatingsParams {
+    #[serde(rename = "type")]
+    pub rating_type: Option<String>,
+}
+
+/// `type=bradley_terry` recomputes the batch MM ratings from every stored
+/// game. `type=advantage_network` recomputes the graph-based potential
+/// instead. Other values aren't backed by a rating implementation in this
+/// crate yet.
+pub async fn list_ratings(
+    State(state): State<SharedState>,
+    Query(params): Query<RatingsParams>,
+) -> impl IntoResponse {
+    match params.rating_type.as_deref().unwrap_or("bradley_terry") {
+        "bradley_terry" => {
+            let mut conn = state.pool.get_connection();
+            let games = match database::games::list_all(&mut conn) {
+                Ok(games) => games,
+                Err(err) => {
+                    log::error!("Failed to load games for rating calculation: {err:?}");
+                    return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+                }
+            };
+            let game_results = to_game_results(&games);
+            let ratings = rating::calculate_ratings(&game_results);
+            Json(ratings).into_response()
+        }
+        "advantage_network" => {
+            let mut conn = state.pool.get_connection();
+            let games = match database::games::list_all(&mut conn) {
+                Ok(games) => games,
+                Err(err) => {
+                    log::error!("Failed to load games for rating calculation: {err:?}");
+                    return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+                }
+            };
+            let game_results = to_game_results(&games);
+            let ratings: RatingMap = rating::calculate_advantage_network_ratings(&game_results);
+            Json(ratings).into_response()
+        }
+        other => (
+            StatusCode::BAD_REQUEST,
+            format!("unsupported rating type: {other}"),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn head_to_head(
+    State(state): State<SharedState>,
+    Path((player_id, other_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    let mut conn = state.pool.get_connection();
+    match database::games::head_to_head_stats(&mut conn, player_id, other_id) {
+        Ok(stats) => Json(stats).into_response(),
+        Err(err) => {
+            log::error!("Failed to load head-to-head stats: {err:?}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PredictParams {
+    pub a: i32,
+    pub b: i32,
+}
+
+pub async fn predict(
+    State(state): State<SharedState>,
+    Query(params): Query<PredictParams>,
+) -> impl IntoResponse {
+    let mut conn = state.pool.get_connection();
+    let latest = match database::ratings::list_latest(&mut conn, "bradley_terry") {
+        Ok(latest) => latest,
+        Err(err) => {
+            log::error!("Failed to load ratings for prediction: {err:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let ratings: RatingMap = latest
+        .iter()
+        .map(|r: &Rating| (r.player_id, r.rating))
+        .collect::<HashMap<_, _>>();
+    let rating_deviations: HashMap<_, _> = latest
+        .into_iter()
+        .map(|r: Rating| (r.player_id, r.rating_deviation))
+        .collect();
+
+    match rating::predict_win_probability_with_confidence(&ratings, &rating_deviations, params.a, params.b) {
+        Some(prediction) => Json(prediction).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            "one or both players have no rating yet",
+        )
+            .into_response(),
+    }
+}
+
+pub async fn trigger_resync(headers: HeaderMap) -> impl IntoResponse {
+    if !auth::is_authorized(&headers) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    tokio::spawn(async move {
+        if let Err(err) = crate::ingest_data(false).await {
+            log::error!("Admin-triggered resync failed: {err:?}");
+        }
+    });
+
+    (StatusCode::ACCEPTED, "Resync triggered").into_response()
+}
+
+pub async fn reset_db(State(state): State<SharedState>, headers: HeaderMap) -> impl IntoResponse {
+    if !auth::is_authorized(&headers) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let mut conn = state.pool.get_connection();
+    match database::migrations::reset_database(&mut conn) {
+        Ok(()) => (StatusCode::OK, "Database reset").into_response(),
+        Err(err) => {
+            log::error!("Admin-triggered reset failed: {err:?}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Stored games are already expanded one row per game won, so the winner is
+/// always `first_player_id` and the loser `second_player_id`.
+fn to_game_results(games: &[Game]) -> Vec<GameResult> {
+    games
+        .iter()
+        .map(|g| GameResult {
+            winner_id: g.first_player_id,
+            loser_id: g.second_player_id,
+            weight: g.weight,
+        })
+        .collect()
+}
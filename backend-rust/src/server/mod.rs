@@ -0,0 +1,17 @@
+pub mod auth;
+pub mod handlers;
+pub mod routes;
+
+pub use routes::create_router;
+
+use std::sync::Arc;
+
+use crate::database::DbPool;
+
+/// Shared state for the axum server: a connection pool, nothing else yet.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: DbPool,
+}
+
+pub type SharedState = Arc<AppState>;
@@ -0,0 +1,20 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::{handlers, SharedState};
+
+pub fn create_router(state: SharedState) -> Router {
+    Router::new()
+        .route("/players", get(handlers::list_players))
+        .route("/ratings", get(handlers::list_ratings))
+        .route(
+            "/players/:id/head-to-head/:other",
+            get(handlers::head_to_head),
+        )
+        .route("/predict", get(handlers::predict))
+        .route("/admin/resync", post(handlers::trigger_resync))
+        .route("/admin/reset", post(handlers::reset_db))
+        .with_state(state)
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use crate::database::models::{Game, NetworkEdge};
+
+type PlayerId = i32;
+
+/// Smoothing constant in the log-odds advantage formula
+/// `ln((sets_a + k) / (sets_b + k))`, keeping a clean sweep (`sets_b == 0`)
+/// from producing an infinite advantage. Shared with `database::network`,
+/// which persists edges computed the same way.
+pub const SMOOTHING_K: f64 = 1.0;
+
+/// Log-odds advantage of a player with `sets_a` sets over one with `sets_b`,
+/// additively smoothed by `SMOOTHING_K`.
+pub fn log_odds_advantage(sets_a: f64, sets_b: f64) -> f64 {
+    ((sets_a + SMOOTHING_K) / (sets_b + SMOOTHING_K)).ln()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    /// Log-odds advantage of the edge's source player over its target player.
+    log_odds: f64,
+    /// Total sets backing this estimate (both directions); doubles as a
+    /// confidence measure when averaging indirect paths.
+    weight: f64,
+}
+
+/// Paths longer than this aren't searched for transitive inference — beyond
+/// a couple of hops the compounded log-odds are dominated by noise, and a
+/// full search blows up combinatorially on a dense graph.
+const MAX_PATH_HOPS: usize = 3;
+
+/// A pairwise network of relative player strengths. Player pairs with no
+/// direct history are predicted by summing log-odds along connecting paths
+/// through common opponents (transitivity assumption), averaged across every
+/// path found, weighted by each path's weakest edge as a confidence proxy.
+pub struct AdvantageGraph {
+    edges: HashMap<(PlayerId, PlayerId), Edge>,
+}
+
+/// Outcome of combining direct history and the wider opponent network for a
+/// single matchup.
+pub struct NetworkPrediction {
+    /// P(player_a wins) estimated from direct games between the two players,
+    /// if any exist.
+    pub direct_history_probability: Option<f64>,
+    /// P(player_a wins) from the network: the direct edge when present,
+    /// otherwise a confidence-weighted average over connecting paths, and
+    /// the rating-based estimate when the graph is too sparse for either.
+    pub network_probability: f64,
+    /// Number of connecting paths used to reach `network_probability`. Zero
+    /// when a direct edge or the rating fallback was used instead.
+    pub connecting_paths: i32,
+}
+
+impl AdvantageGraph {
+    /// Builds the graph from raw game rows — used to (re)populate the
+    /// persisted `network` table; prefer `from_network_edges` to serve a
+    /// request, since that skips the full games-table scan.
+    pub fn build(games: &[Game]) -> Self {
+        let mut sets_won: HashMap<(PlayerId, PlayerId), f64> = HashMap::new();
+
+        for game in games {
+            let (a, b) = (game.first_player_id, game.second_player_id);
+            *sets_won.entry((a, b)).or_insert(0.0) += game.first_player_score as f64;
+            *sets_won.entry((b, a)).or_insert(0.0) += game.second_player_score as f64;
+        }
+
+        let pairs = sets_won
+            .iter()
+            .filter(|&(&(a, b), _)| a < b)
+            .map(|(&(a, b), &sets_a)| {
+                let sets_b = *sets_won.get(&(b, a)).unwrap_or(&0.0);
+                (a, b, sets_a, sets_b)
+            });
+
+        Self::from_pairs(pairs)
+    }
+
+    /// Loads the graph straight from the persisted `network` table, skipping
+    /// the full games-table scan `build` does — the read path a head-to-head
+    /// request should use.
+    pub fn from_network_edges(edges: &[NetworkEdge]) -> Self {
+        Self::from_pairs(
+            edges
+                .iter()
+                .map(|e| (e.player_a, e.player_b, e.sets_a as f64, e.sets_b as f64)),
+        )
+    }
+
+    fn from_pairs(pairs: impl Iterator<Item = (PlayerId, PlayerId, f64, f64)>) -> Self {
+        let mut edges = HashMap::new();
+
+        for (a, b, sets_a, sets_b) in pairs {
+            let weight = sets_a + sets_b;
+            if weight <= 0.0 {
+                continue;
+            }
+            let log_odds = log_odds_advantage(sets_a, sets_b);
+            edges.insert((a, b), Edge { log_odds, weight });
+            edges.insert((b, a), Edge { log_odds: -log_odds, weight });
+        }
+
+        Self { edges }
+    }
+
+    fn direct_edge(&self, a: PlayerId, b: PlayerId) -> Option<Edge> {
+        self.edges.get(&(a, b)).copied()
+    }
+
+    fn neighbors(&self, a: PlayerId) -> impl Iterator<Item = PlayerId> + '_ {
+        self.edges.keys().filter(move |&&(from, _)| from == a).map(|&(_, to)| to)
+    }
+
+    /// Every simple path from `a` to `b` of at most `MAX_PATH_HOPS` edges, as
+    /// `(summed log-odds, weakest edge weight)` pairs. Log-odds are additive
+    /// along a path under the transitivity assumption (A beats C by X,
+    /// C beats B by Y implies A beats B by roughly X + Y).
+    fn transitive_paths(&self, a: PlayerId, b: PlayerId) -> Vec<(f64, f64)> {
+        let mut paths = Vec::new();
+        let mut visited = vec![a];
+        self.walk(a, b, 0.0, f64::INFINITY, &mut visited, &mut paths);
+        paths
+    }
+
+    fn walk(
+        &self,
+        current: PlayerId,
+        target: PlayerId,
+        log_odds_so_far: f64,
+        min_weight_so_far: f64,
+        visited: &mut Vec<PlayerId>,
+        paths: &mut Vec<(f64, f64)>,
+    ) {
+        if visited.len() > MAX_PATH_HOPS {
+            return;
+        }
+
+        for next in self.neighbors(current).collect::<Vec<_>>() {
+            if visited.contains(&next) {
+                continue;
+            }
+
+            let edge = self
+                .direct_edge(current, next)
+                .expect("neighbors() only yields players with a direct edge");
+            let log_odds = log_odds_so_far + edge.log_odds;
+            let min_weight = min_weight_so_far.min(edge.weight);
+
+            if next == target {
+                paths.push((log_odds, min_weight));
+                continue;
+            }
+
+            visited.push(next);
+            self.walk(next, target, log_odds, min_weight, visited, paths);
+            visited.pop();
+        }
+    }
+
+    pub fn predict(&self, a: PlayerId, b: PlayerId, rating_fallback_probability: f64) -> NetworkPrediction {
+        if let Some(edge) = self.direct_edge(a, b) {
+            let probability = logistic(edge.log_odds);
+            return NetworkPrediction {
+                direct_history_probability: Some(probability),
+                network_probability: probability,
+                connecting_paths: 0,
+            };
+        }
+
+        let paths = self.transitive_paths(a, b);
+        if paths.is_empty() {
+            return NetworkPrediction {
+                direct_history_probability: None,
+                network_probability: rating_fallback_probability,
+                connecting_paths: 0,
+            };
+        }
+
+        let confidence_total: f64 = paths.iter().map(|&(_, confidence)| confidence).sum();
+        let weighted_log_odds: f64 = paths.iter().map(|&(log_odds, confidence)| log_odds * confidence).sum();
+
+        let network_probability = if confidence_total > 0.0 {
+            logistic(weighted_log_odds / confidence_total)
+        } else {
+            rating_fallback_probability
+        };
+
+        NetworkPrediction {
+            direct_history_probability: None,
+            network_probability,
+            connecting_paths: paths.len() as i32,
+        }
+    }
+}
+
+fn logistic(log_odds: f64) -> f64 {
+    1.0 / (1.0 + (-log_odds).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn game(a: PlayerId, b: PlayerId, sets_a: i32, sets_b: i32) -> Game {
+        Game {
+            id: 0,
+            tournament_id: 0,
+            first_player_id: a,
+            second_player_id: b,
+            first_player_score: sets_a,
+            second_player_score: sets_b,
+            date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+            weight: 1.0,
+            created_at: None,
+            discipline: "9ball".to_string(),
+        }
+    }
+
+    #[test]
+    fn log_odds_advantage_is_zero_for_an_even_split() {
+        assert_eq!(log_odds_advantage(3.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn log_odds_advantage_favors_the_clean_sweep_without_going_infinite() {
+        let advantage = log_odds_advantage(3.0, 0.0);
+        assert!(advantage.is_finite());
+        assert!(advantage > 0.0);
+    }
+
+    #[test]
+    fn predict_uses_direct_history_when_the_pair_has_played() {
+        let graph = AdvantageGraph::build(&[game(1, 2, 3, 1)]);
+
+        let prediction = graph.predict(1, 2, 0.5);
+
+        assert!(prediction.direct_history_probability.is_some());
+        assert_eq!(prediction.connecting_paths, 0);
+        assert!(prediction.network_probability > 0.5);
+    }
+
+    #[test]
+    fn predict_infers_a_transitive_matchup_through_a_common_opponent() {
+        // 1 beats 3, 3 beats 2, but 1 and 2 have never played directly.
+        let graph = AdvantageGraph::build(&[game(1, 3, 3, 0), game(3, 2, 3, 0)]);
+
+        let prediction = graph.predict(1, 2, 0.5);
+
+        assert!(prediction.direct_history_probability.is_none());
+        assert!(prediction.connecting_paths > 0);
+        assert!(prediction.network_probability > 0.5);
+    }
+
+    #[test]
+    fn predict_falls_back_to_the_rating_estimate_when_the_pair_is_unconnected() {
+        let graph = AdvantageGraph::build(&[game(1, 2, 3, 0)]);
+
+        let prediction = graph.predict(3, 4, 0.42);
+
+        assert_eq!(prediction.direct_history_probability, None);
+        assert_eq!(prediction.connecting_paths, 0);
+        assert_eq!(prediction.network_probability, 0.42);
+    }
+}
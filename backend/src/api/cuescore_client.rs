@@ -1,17 +1,27 @@
 use crate::api::parsers;
 use crate::cache::Cache;
+use crate::config::settings::ScraperSettings;
 use crate::domain::models::{Tournament, TournamentResponse};
 use crate::http::RateLimitedClient;
 use crate::pagination::{PageIterator, PaginationConfig};
 use anyhow::{Context, Result};
 use log::{info, warn};
 use serde_json::Value;
+use std::time::Duration;
 
 const API_BASE_URL: &str = "https://api.cuescore.com";
 const RATE_LIMIT_MS: u64 = 100;
 const USER_AGENT: &str = "WarsawPoolRankings/2.0";
 const TIMEOUT_SECS: u64 = 30;
 
+/// Raw cached tournament JSON is trusted for this long before being
+/// re-fetched, on top of (not instead of) the per-tournament invalidation
+/// `IngestionService::needs_refresh` already does from `tournament_sync`.
+/// Guards against a tournament that's fallen out of the DB-tracked sync
+/// state (e.g. a fresh cache directory with no matching DB rows yet) from
+/// being treated as permanently fresh.
+const TOURNAMENT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
 /// CueScore API client
 pub struct CueScoreClient {
     client: RateLimitedClient,
@@ -19,16 +29,32 @@ pub struct CueScoreClient {
 
 impl CueScoreClient {
     /// Create a new CueScore API client
-    pub fn new() -> Result<Self> {
-        let client = RateLimitedClient::new(USER_AGENT, TIMEOUT_SECS, RATE_LIMIT_MS)?;
+    pub fn new(settings: &ScraperSettings) -> Result<Self> {
+        let client = RateLimitedClient::new(
+            USER_AGENT,
+            TIMEOUT_SECS,
+            RATE_LIMIT_MS,
+            settings.max_retries,
+            settings.base_backoff_ms,
+        )?;
         Ok(Self { client })
     }
 
-    /// Fetch all tournaments for a venue
+    /// Fetch all tournaments for a venue, paging through the results.
     pub async fn fetch_venue_tournaments(&mut self, venue_id: i64) -> Result<Vec<Tournament>> {
         info!("Fetching tournaments for venue {}", venue_id);
 
-        let config = PaginationConfig::new();
+        let tournaments = self.fetch_paged(venue_id, PaginationConfig::new()).await?;
+
+        info!(
+            "Fetched {} tournaments for venue {}",
+            tournaments.len(),
+            venue_id
+        );
+        Ok(tournaments)
+    }
+
+    async fn fetch_paged(&mut self, venue_id: i64, config: PaginationConfig) -> Result<Vec<Tournament>> {
         let mut pages = PageIterator::new(config);
         let tournaments = Vec::new();
 
@@ -54,11 +80,6 @@ impl CueScoreClient {
             pages.advance();
         }
 
-        info!(
-            "Fetched {} tournaments for venue {}",
-            tournaments.len(),
-            venue_id
-        );
         Ok(tournaments)
     }
 
@@ -84,8 +105,8 @@ impl CueScoreClient {
         tournament_id: i64,
         cache: &Cache,
     ) -> Result<Option<TournamentResponse>> {
-        // 1. Try load from cache
-        let cached_value = cache.load_raw(&tournament_id.to_string())?;
+        // 1. Try load from cache, ignoring entries older than the TTL
+        let cached_value = cache.load_fresh_raw(&tournament_id.to_string(), TOURNAMENT_CACHE_TTL)?;
 
         let json_value = if let Some(val) = cached_value {
             val
@@ -60,13 +60,15 @@ pub async fn get_players(
         name_contains: params.filter,
         min_games: Some(state.config.rating.min_ranked_games),
         rating_type,
+        discipline: None,
         sort_by,
         sort_order,
         limit: page_size,
         offset,
+        cursor: None,
     };
 
-    let (rows, total) = match database::ratings::list_ranked_players(&mut conn, &filter) {
+    let (rows, total, _next_cursor) = match database::ratings::list_ranked_players(&mut conn, &filter) {
         Ok(result) => result,
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error: {}", e)).into_response(),
     };
@@ -170,8 +172,8 @@ pub async fn admin_refresh(
     tokio::spawn(async move {
         log::info!("Admin triggered refresh started");
         let ingest_result = async {
-            let mut ingest_service = IngestionService::new()?;
-            ingest_service.run().await
+            let mut ingest_service = IngestionService::new(state.config.clone())?;
+            ingest_service.run(false).await
         }.await;
         if let Err(e) = ingest_result {
             log::error!("Refresh failed at ingestion: {:?}", e);
@@ -1,29 +1,25 @@
 use axum::{
     extract::State,
-    http::{StatusCode, HeaderMap},
+    http::StatusCode,
     response::IntoResponse,
 };
 use std::sync::Arc;
 use log;
 
+use crate::auth::AdminClaims;
 use crate::services::ingestion::IngestionService;
 use crate::services::processing::ProcessingService;
 use super::AppState;
 
 pub async fn admin_refresh(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
+    claims: AdminClaims,
 ) -> impl IntoResponse {
-    let auth_header = headers.get("Authorization").and_then(|h| h.to_str().ok());
-    if auth_header != Some("Bearer secret") {
-        return StatusCode::UNAUTHORIZED.into_response();
-    }
-
     tokio::spawn(async move {
-        log::info!("Admin triggered refresh started");
+        log::info!("Admin triggered refresh started (subject: {})", claims.sub);
         let ingest_result = async {
-            let mut ingest_service = IngestionService::new()?;
-            ingest_service.run().await
+            let mut ingest_service = IngestionService::new(state.config.clone())?;
+            ingest_service.run(false).await
         }.await;
         if let Err(e) = ingest_result {
             log::error!("Refresh failed at ingestion: {:?}", e);
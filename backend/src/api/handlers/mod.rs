@@ -7,6 +7,7 @@ use crate::config::settings::AppConfig;
 
 pub mod players;
 pub mod admin;
+pub mod seeding;
 
 #[derive(Clone)] // AppState usually needs Clone if used in FromRef, but here we use Arc<AppState> so it's fine.
 pub struct AppState {
@@ -22,4 +23,12 @@ pub struct PlayerParams {
     pub order: Option<String>,
     pub filter: Option<String>,
     pub rating_type: Option<String>,
+    pub discipline: Option<String>,
+    /// Opaque cursor from a previous response's `nextCursor`. When set, seeks
+    /// past it instead of applying `page`/`page_size` as an offset.
+    pub cursor: Option<String>,
+    /// `predict_matchup`'s win-probability scale: `"elo"` selects
+    /// `RatingSettings::elo_prediction_scale`, anything else (including
+    /// absent) keeps the default `RatingSettings::prediction_scale`.
+    pub scale: Option<String>,
 }
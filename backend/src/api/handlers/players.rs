@@ -6,8 +6,10 @@ use axum::{
 use std::sync::Arc;
 use urlencoding::encode;
 
-use crate::api::models::{PlayerListItem, PlayerListResponse, PlayerDetail, HeadToHeadMatch, HeadToHeadResponse, HeadToHeadStats};
-use crate::database::{self, models::{PlayerFilter, SortColumn, SortOrder}};
+use crate::advantage_graph::AdvantageGraph;
+use crate::api::models::{PlayerListItem, PlayerListResponse, PlayerDetail, PlayerRatingHistoryResponse, RatingHistoryPoint, HeadToHeadMatch, HeadToHeadResponse, HeadToHeadStats, PlayerHistoryGame, PlayerHistoryResponse, PlayerHistoryStats};
+use crate::database::{self, cursor::PlayerCursor, models::{PlayerFilter, SortColumn, SortOrder}};
+use crate::domain::Discipline;
 use super::{AppState, PlayerParams};
 
 pub async fn get_players(
@@ -36,17 +38,27 @@ pub async fn get_players(
         Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "DB Connection Error").into_response(),
     };
 
+    let discipline = params.discipline.as_deref().and_then(Discipline::from_slug);
+
+    let cursor = match params.cursor.as_deref().map(PlayerCursor::decode) {
+        Some(Ok(cursor)) => Some(cursor),
+        Some(Err(e)) => return (StatusCode::BAD_REQUEST, format!("Invalid cursor: {}", e)).into_response(),
+        None => None,
+    };
+
     let filter = PlayerFilter {
         name_contains: params.filter,
         min_games: Some(state.config.rating.min_ranked_games),
         rating_type,
+        discipline,
         sort_by,
         sort_order,
         limit: page_size,
         offset,
+        cursor,
     };
 
-    let (rows, total) = match database::ratings::list_ranked_players(&mut conn, &filter) {
+    let (rows, total, next_cursor) = match database::ratings::list_ranked_players(&mut conn, &filter) {
         Ok(result) => result,
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error: {}", e)).into_response(),
     };
@@ -54,6 +66,7 @@ pub async fn get_players(
     let players: Vec<PlayerListItem> = rows.into_iter().enumerate().map(|(i, row)| {
         let player_id_i32 = row.player_id;
         let matches_played = database::games::count_matches_played_for_player(&mut conn, player_id_i32).unwrap_or(0);
+        let rating_delta = database::rating_history::delta_since_previous_period(&mut conn, player_id_i32, &rating_type).unwrap_or(None);
         PlayerListItem {
             rank: (offset + i + 1) as i32,
             player_id: row.player_id as i64,
@@ -64,6 +77,9 @@ pub async fn get_players(
             games_played: row.games_played,
             confidence_level: row.confidence_level,
             matches_played,
+            rating_delta,
+            rating_deviation: row.rating_deviation,
+            volatility: row.volatility,
         }
     }).collect();
 
@@ -72,6 +88,7 @@ pub async fn get_players(
         total: total as i32,
         page: page as i32,
         page_size: page_size as i32,
+        next_cursor,
     }).into_response()
 }
 
@@ -129,6 +146,8 @@ pub async fn get_player_detail(
                 row.cuescore_id.unwrap_or(0)
             );
 
+            let rating_delta = database::rating_history::delta_since_previous_period(&mut conn, row.player_id, &rating_type).unwrap_or(None);
+
             Json(PlayerDetail {
                 player_id: row.player_id as i64,
                 cuescore_id: row.cuescore_id,
@@ -144,12 +163,41 @@ pub async fn get_player_detail(
                 effective_games: row.games_played,
                 last_played,
                 matches_played,
+                rating_delta,
+                rating_deviation: row.rating_deviation,
+                volatility: row.volatility,
             }).into_response()
         },
         None => StatusCode::NOT_FOUND.into_response(),
     }
 }
 
+pub async fn get_player_rating_history(
+    State(state): State<Arc<AppState>>,
+    Path(player_id): Path<i64>,
+    Query(params): Query<PlayerParams>,
+) -> impl IntoResponse {
+    let rating_type = params.rating_type.unwrap_or_else(|| "all".to_string());
+
+    let mut conn = match state.pool.get() {
+        Ok(conn) => conn,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "DB Connection Error").into_response(),
+    };
+
+    let history = match database::rating_history::list_for_player(&mut conn, player_id as i32, &rating_type) {
+        Ok(h) => h,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error: {}", e)).into_response(),
+    };
+
+    let points = history.into_iter().map(|entry| RatingHistoryPoint {
+        period_date: entry.period_date.to_string(),
+        rating: entry.rating,
+        games_played: entry.games_played,
+    }).collect();
+
+    Json(PlayerRatingHistoryResponse { player_id, points }).into_response()
+}
+
 pub async fn get_head_to_head_comparison(
     State(state): State<Arc<AppState>>,
     Path((player1_id, player2_id)): Path<(i64, i64)>,
@@ -173,14 +221,29 @@ pub async fn get_head_to_head_comparison(
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error for Player 2: {}", e)).into_response(),
     };
 
-    let rating_diff = player1_detail_data.rating - player2_detail_data.rating;
-    let probability_p1_wins = 1.0 / (1.0 + (-rating_diff * std::f64::consts::LN_2 / 100.0).exp());
+    // Same formula `predict_matchup` (`/api/predict`) uses, so the two
+    // endpoints never quietly disagree on a player's win probability.
+    let probability_p1_wins = crate::rating::predict_win_probability_with_scale(
+        player1_detail_data.rating,
+        player2_detail_data.rating,
+        state.config.rating.prediction_scale,
+    );
 
     let matches = match database::games::get_head_to_head_matches(&mut conn, player1_detail_data.player_id, player2_detail_data.player_id) {
         Ok(m) => m,
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error for matches: {}", e)).into_response(),
     };
 
+    let network_edges = match database::network::list_all(&mut conn) {
+        Ok(edges) => edges,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error for network: {}", e)).into_response(),
+    };
+    let network_prediction = AdvantageGraph::from_network_edges(&network_edges).predict(
+        player1_detail_data.player_id,
+        player2_detail_data.player_id,
+        probability_p1_wins,
+    );
+
     let mut stats = HeadToHeadStats {
         total_matches: matches.len() as i32,
         player1_match_wins: 0,
@@ -232,6 +295,10 @@ pub async fn get_head_to_head_comparison(
         let encoded_name = encode(&p.name).replace(' ', "+");
         let cuescore_profile_url = format!("https://cuescore.com/player/{}/{}", encoded_name, p.cuescore_id.unwrap_or(0));
 
+        let rating_delta = database::rating_history::delta_since_previous_period(&mut conn, p.player_id, &rating_type).unwrap_or(None);
+        let rating_deviation = p.rating_deviation;
+        let volatility = p.volatility;
+
         PlayerDetail {
             player_id: p.player_id as i64,
             cuescore_id: p.cuescore_id,
@@ -247,6 +314,9 @@ pub async fn get_head_to_head_comparison(
             effective_games: p.games_played,
             last_played,
             matches_played,
+            rating_delta,
+            rating_deviation,
+            volatility,
         }
     };
 
@@ -257,7 +327,119 @@ pub async fn get_head_to_head_comparison(
         player1: Some(player1_api_detail),
         player2: Some(player2_api_detail),
         probability_p1_wins,
+        direct_history_probability: network_prediction.direct_history_probability,
+        network_probability: network_prediction.network_probability,
+        connecting_paths: network_prediction.connecting_paths,
         matches: h2h_matches,
         stats: Some(stats),
     }).into_response()
 }
+
+/// Matchup forecast for two players, without `/api/compare`'s match history
+/// or network-advantage lookup — just the rating-based probability.
+/// `?scale=elo` switches from `RatingSettings::prediction_scale` to
+/// `RatingSettings::elo_prediction_scale`, for callers that think in
+/// Elo-familiar point gaps (e.g. a 200-point gap reading like Elo's
+/// familiar 400-point one) instead of this crate's Fargo-like default.
+pub async fn predict_matchup(
+    State(state): State<Arc<AppState>>,
+    Path((player1_id, player2_id)): Path<(i64, i64)>,
+    Query(params): Query<PlayerParams>,
+) -> impl IntoResponse {
+    let rating_type = params.rating_type.unwrap_or_else(|| "all".to_string());
+    let scale = if params.scale.as_deref() == Some("elo") {
+        // `10^(d / S)` is `exp(d / (S / ln 10))`, so the Elo-style base-10
+        // scale is converted to the logistic's natural-log scale here.
+        state.config.rating.elo_prediction_scale / std::f64::consts::LN_10
+    } else {
+        state.config.rating.prediction_scale
+    };
+
+    let mut conn = match state.pool.get() {
+        Ok(conn) => conn,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "DB Connection Error").into_response(),
+    };
+
+    let player1 = match database::ratings::get_player_rating_detail(&mut conn, player1_id as i32, &rating_type) {
+        Ok(Some(p)) => p,
+        Ok(None) => return (StatusCode::NOT_FOUND, format!("Player 1 ({}) not found", player1_id)).into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error for Player 1: {}", e)).into_response(),
+    };
+    let player2 = match database::ratings::get_player_rating_detail(&mut conn, player2_id as i32, &rating_type) {
+        Ok(Some(p)) => p,
+        Ok(None) => return (StatusCode::NOT_FOUND, format!("Player 2 ({}) not found", player2_id)).into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error for Player 2: {}", e)).into_response(),
+    };
+
+    let probability_p1_wins = crate::rating::predict_win_probability_with_scale(
+        player1.rating,
+        player2.rating,
+        scale,
+    );
+
+    Json(crate::api::models::MatchPrediction {
+        player1_id,
+        player2_id,
+        rating_type,
+        probability_p1_wins,
+        player1_games_played: player1.games_played,
+        player2_games_played: player2.games_played,
+        player1_confidence_level: player1.confidence_level,
+        player2_confidence_level: player2.confidence_level,
+    }).into_response()
+}
+
+/// `GET /api/players/:a/history/:b` — every individual game the two players have
+/// played against each other, oldest first, plus an aggregate win count.
+/// This is the data `predict_matchup`'s win probabilities are ultimately
+/// validated against.
+pub async fn get_player_history(
+    State(state): State<Arc<AppState>>,
+    Path((player_a_id, player_b_id)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let mut conn = match state.pool.get() {
+        Ok(conn) => conn,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "DB Connection Error").into_response(),
+    };
+
+    let games = match database::games::list_games_between(&mut conn, player_a_id as i32, player_b_id as i32) {
+        Ok(games) => games,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query Error: {}", e)).into_response(),
+    };
+
+    let mut stats = PlayerHistoryStats {
+        player_a_wins: 0,
+        player_b_wins: 0,
+        total_games: games.len() as i32,
+    };
+
+    let history: Vec<PlayerHistoryGame> = games.iter().map(|g| {
+        let winner_id = if g.first_player_score > g.second_player_score {
+            g.first_player_id
+        } else {
+            g.second_player_id
+        };
+
+        if winner_id as i64 == player_a_id {
+            stats.player_a_wins += 1;
+        } else {
+            stats.player_b_wins += 1;
+        }
+
+        PlayerHistoryGame {
+            date: g.date.to_string(),
+            first_player_id: g.first_player_id as i64,
+            second_player_id: g.second_player_id as i64,
+            first_player_score: g.first_player_score,
+            second_player_score: g.second_player_score,
+            winner_id: winner_id as i64,
+        }
+    }).collect();
+
+    Json(PlayerHistoryResponse {
+        player_a_id,
+        player_b_id,
+        games: history,
+        stats,
+    }).into_response()
+}
@@ -0,0 +1,62 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Json},
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::models::{SeedingRequest, SeedingResponse};
+use crate::database;
+use crate::seeding::{generate_seeding, SeedSlot};
+use super::AppState;
+
+pub async fn get_seeding(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SeedingRequest>,
+) -> impl IntoResponse {
+    if request.player_ids.len() < 2 {
+        return (
+            StatusCode::BAD_REQUEST,
+            "player_ids must contain at least 2 players",
+        )
+            .into_response();
+    }
+
+    let mut conn = match state.pool.get() {
+        Ok(conn) => conn,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "DB Connection Error").into_response(),
+    };
+
+    let rating_type = request.rating_type.unwrap_or_else(|| "all".to_string());
+    let mut ratings = HashMap::new();
+    for &player_id in &request.player_ids {
+        match database::ratings::get_latest_for_player(&mut conn, player_id as i32, &rating_type) {
+            Ok(Some(rating)) => {
+                ratings.insert(player_id, rating.rating);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, format!("Query error: {}", e))
+                    .into_response()
+            }
+        }
+    }
+
+    let result = generate_seeding(&request.player_ids, &ratings);
+    let seeds = result
+        .seeds
+        .into_iter()
+        .map(|slot| match slot {
+            SeedSlot::Player(player_id) => Some(player_id),
+            SeedSlot::Bye => None,
+        })
+        .collect();
+
+    Json(SeedingResponse {
+        seeds,
+        expected_upset_score: result.expected_upset_score,
+        expected_correct_matches: result.expected_correct_matches,
+    })
+    .into_response()
+}
@@ -1,15 +1,39 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerListItem {
-    pub rank: usize,
+    pub rank: i32,
     pub player_id: i64,
     pub cuescore_id: Option<i64>,
     pub name: String,
+    pub avatar_url: Option<String>,
     pub rating: f64,
     pub games_played: i32,
     pub confidence_level: String,
+    pub matches_played: i32,
+    /// Rating change since the previous recompute period, e.g. `12.0` for a
+    /// 12-point gain. `None` until a second rating_history snapshot exists.
+    pub rating_delta: Option<f64>,
+    /// Rating deviation: grows with time since the player's last game. See
+    /// `rating::deviation::compute_rating_deviation`. Under Glicko-2 it is
+    /// that algorithm's own deviation instead.
+    pub rating_deviation: f64,
+    /// Glicko-2 volatility. `None` unless the ratings were computed with
+    /// `RatingAlgorithm::Glicko2`.
+    pub volatility: Option<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerListResponse {
+    pub items: Vec<PlayerListItem>,
+    pub total: i32,
+    pub page: i32,
+    pub page_size: i32,
+    /// Opaque token for the next page via `?cursor=`, seeking past the last
+    /// row instead of re-scanning `OFFSET` rows. `None` on the last page.
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -27,6 +51,8 @@ pub struct PlayerDetail {
     pub player_id: i64,
     pub cuescore_id: Option<i64>,
     pub name: String,
+    pub cuescore_profile_url: String,
+    pub avatar_url: Option<String>,
     pub rating: f64,
     pub games_played: i32,
     pub confidence_level: String,
@@ -35,4 +61,132 @@ pub struct PlayerDetail {
     pub ml_weight: f64,
     pub effective_games: i32,
     pub last_played: Option<String>,
+    pub matches_played: i32,
+    pub rating_delta: Option<f64>,
+    pub rating_deviation: f64,
+    pub volatility: Option<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingHistoryPoint {
+    pub period_date: String,
+    pub rating: f64,
+    pub games_played: i32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRatingHistoryResponse {
+    pub player_id: i64,
+    pub points: Vec<RatingHistoryPoint>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadToHeadMatch {
+    pub date: String,
+    pub tournament_name: String,
+    pub player1_wins: i32,
+    pub player2_wins: i32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadToHeadStats {
+    pub total_matches: i32,
+    pub player1_match_wins: i32,
+    pub player2_match_wins: i32,
+    pub total_frames: i32,
+    pub player1_frame_wins: i32,
+    pub player2_frame_wins: i32,
+}
+
+/// Lightweight matchup forecast: just the Bradley-Terry win probability for
+/// two players, with none of `/api/compare`'s match history or stats. Meant
+/// for callers (e.g. bracket/seeding tools) that only need a probability.
+/// `?scale=elo` switches the logistic's scale from `RatingSettings::prediction_scale`
+/// to `RatingSettings::elo_prediction_scale`, for callers that think in
+/// Elo-familiar point gaps instead of this crate's Fargo-like one.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchPrediction {
+    pub player1_id: i64,
+    pub player2_id: i64,
+    pub rating_type: String,
+    /// `P(player1 beats player2)` from the fitted ratings:
+    /// `1 / (1 + exp(-(rating1 - rating2) / s))`.
+    pub probability_p1_wins: f64,
+    pub player1_games_played: i32,
+    pub player2_games_played: i32,
+    pub player1_confidence_level: String,
+    pub player2_confidence_level: String,
+}
+
+/// One game from `GET /api/players/:a/history/:b`'s rivalry view.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerHistoryGame {
+    pub date: String,
+    pub first_player_id: i64,
+    pub second_player_id: i64,
+    pub first_player_score: i32,
+    pub second_player_score: i32,
+    pub winner_id: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerHistoryStats {
+    pub player_a_wins: i32,
+    pub player_b_wins: i32,
+    pub total_games: i32,
+}
+
+/// Full pairwise match history between two players, for the frontend's
+/// rivalry view. The win-probability endpoints do not read it.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerHistoryResponse {
+    pub player_a_id: i64,
+    pub player_b_id: i64,
+    pub games: Vec<PlayerHistoryGame>,
+    pub stats: PlayerHistoryStats,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadToHeadResponse {
+    pub player1: Option<PlayerDetail>,
+    pub player2: Option<PlayerDetail>,
+    /// P(player1 wins) from the global rating difference alone.
+    pub probability_p1_wins: f64,
+    /// P(player1 wins) from their direct games only, if any exist.
+    pub direct_history_probability: Option<f64>,
+    /// P(player1 wins) from the relative-advantage network: direct history
+    /// when available, otherwise common-opponent paths, otherwise
+    /// `probability_p1_wins`.
+    pub network_probability: f64,
+    /// Number of common-opponent paths backing `network_probability`. Zero
+    /// when it came from direct history or the rating fallback.
+    pub connecting_paths: i32,
+    pub matches: Vec<HeadToHeadMatch>,
+    pub stats: Option<HeadToHeadStats>,
+}
+
+#[derive(Deserialize)]
+pub struct SeedingRequest {
+    pub player_ids: Vec<i64>,
+    pub rating_type: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedingResponse {
+    /// Bracket slots in seed order; `None` marks a bye.
+    pub seeds: Vec<Option<i64>>,
+    pub expected_upset_score: f64,
+    /// Expected number of "chalk" (higher-rated player advances) results
+    /// across the whole bracket. See `seeding::score_all_rounds`.
+    pub expected_correct_matches: f64,
 }
@@ -3,13 +3,17 @@ use axum::{
     Router,
 };
 use std::sync::Arc;
-use crate::api::handlers::{players::{get_players, get_player_detail, get_head_to_head_comparison}, admin::admin_refresh, AppState};
+use crate::api::handlers::{players::{get_players, get_player_detail, get_player_rating_history, get_head_to_head_comparison, predict_matchup, get_player_history}, admin::admin_refresh, seeding::get_seeding, AppState};
 
 pub fn create_router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/api/players", get(get_players))
         .route("/api/player/:id", get(get_player_detail))
+        .route("/api/player/:id/history", get(get_player_rating_history))
         .route("/api/compare/:player1_id/:player2_id", get(get_head_to_head_comparison))
+        .route("/api/predict/:player1_id/:player2_id", get(predict_matchup))
+        .route("/api/players/:player_a_id/history/:player_b_id", get(get_player_history))
+        .route("/api/seeding", post(get_seeding))
         .route("/api/admin/refresh", post(admin_refresh))
         .with_state(state)
 }
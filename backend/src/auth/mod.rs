@@ -0,0 +1,139 @@
+use anyhow::Result;
+use axum::extract::FromRequestParts;
+use axum::http::{request::Parts, StatusCode};
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::api::handlers::AppState;
+use crate::config::settings::AdminSettings;
+
+/// Claims carried by an admin-route bearer token: who it was minted for
+/// (`sub`), who minted it (`iss`), and the usual issued-at/expiry pair.
+/// `jsonwebtoken::decode` checks `exp` and (via `Validation::set_issuer`)
+/// `iss` for us; `sub` is only read back out for logging.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdminClaims {
+    pub sub: String,
+    pub iss: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Mint an HS256 token for `subject`, valid for `settings.token_ttl_secs`
+/// from now. Used by the `mint-admin-token` CLI command; not exposed over
+/// HTTP so minting itself never needs its own auth story.
+pub fn mint_admin_token(settings: &AdminSettings, subject: &str) -> Result<String> {
+    let now = Utc::now().timestamp();
+    let claims = AdminClaims {
+        sub: subject.to_string(),
+        iss: settings.issuer.clone(),
+        iat: now,
+        exp: now + settings.token_ttl_secs as i64,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(settings.signing_secret.as_bytes()),
+    )?;
+    Ok(token)
+}
+
+impl FromRequestParts<Arc<AppState>> for AdminClaims {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get("Authorization")
+            .and_then(|h| h.to_str().ok())
+            .ok_or((StatusCode::UNAUTHORIZED, "Missing Authorization header".to_string()))?;
+
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or((StatusCode::UNAUTHORIZED, "Expected a Bearer token".to_string()))?;
+
+        let settings = &state.config.admin;
+        let mut validation = Validation::default();
+        validation.set_issuer(&[&settings.issuer]);
+
+        let data = decode::<AdminClaims>(
+            token,
+            &DecodingKey::from_secret(settings.signing_secret.as_bytes()),
+            &validation,
+        )
+        .map_err(|e| (StatusCode::FORBIDDEN, format!("Invalid admin token: {e}")))?;
+
+        Ok(data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> AdminSettings {
+        AdminSettings {
+            signing_secret: "test-secret".to_string(),
+            issuer: "warsaw-pool-ranking".to_string(),
+            token_ttl_secs: 3600,
+        }
+    }
+
+    #[test]
+    fn mint_admin_token_round_trips_through_decode() {
+        let settings = settings();
+        let token = mint_admin_token(&settings, "operator").unwrap();
+
+        let mut validation = Validation::default();
+        validation.set_issuer(&[&settings.issuer]);
+        let claims = decode::<AdminClaims>(
+            &token,
+            &DecodingKey::from_secret(settings.signing_secret.as_bytes()),
+            &validation,
+        )
+        .unwrap()
+        .claims;
+
+        assert_eq!(claims.sub, "operator");
+        assert_eq!(claims.iss, settings.issuer);
+        assert_eq!(claims.exp - claims.iat, settings.token_ttl_secs as i64);
+    }
+
+    #[test]
+    fn decode_rejects_a_token_signed_with_a_different_secret() {
+        let settings = settings();
+        let token = mint_admin_token(&settings, "operator").unwrap();
+
+        let mut validation = Validation::default();
+        validation.set_issuer(&[&settings.issuer]);
+        let result = decode::<AdminClaims>(
+            &token,
+            &DecodingKey::from_secret(b"wrong-secret"),
+            &validation,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_rejects_a_token_minted_for_a_different_issuer() {
+        let settings = settings();
+        let token = mint_admin_token(&settings, "operator").unwrap();
+
+        let mut validation = Validation::default();
+        validation.set_issuer(&["some-other-issuer"]);
+        let result = decode::<AdminClaims>(
+            &token,
+            &DecodingKey::from_secret(settings.signing_secret.as_bytes()),
+            &validation,
+        );
+
+        assert!(result.is_err());
+    }
+}
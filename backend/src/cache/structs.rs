@@ -1,9 +1,26 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use log::info;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Sidecar metadata written alongside a cache entry's `{key}.json` file as
+/// `{key}.meta.json`, so `load_fresh`/`is_stale` can tell how old an entry
+/// is without touching the (possibly large) payload itself.
+#[derive(Serialize, Deserialize)]
+struct CacheMeta {
+    written_at: DateTime<Utc>,
+    /// Hash of the serialized payload at write time. Not currently compared
+    /// against anything (there's no upstream ETag to check it against yet),
+    /// but recorded so a future source-side hash/ETag can be diffed against
+    /// it without another cache format migration.
+    content_hash: u64,
+}
 
 /// File-based cache for tournament data with two-tier system
 pub struct Cache {
@@ -89,6 +106,23 @@ impl Cache {
         self.read_json_opt(&file_path)
     }
 
+    /// Drop a raw cache entry so the next `fetch_and_cache_tournament` call
+    /// re-fetches it instead of reusing stale data. A no-op if nothing is
+    /// cached under `id`.
+    pub fn invalidate_raw(&self, id: &str) -> Result<()> {
+        let file_path = self.build_raw_path(id);
+        if file_path.exists() {
+            fs::remove_file(&file_path).context("Failed to invalidate raw cache entry")?;
+        }
+
+        let meta_path = Self::meta_path_for(&file_path);
+        if meta_path.exists() {
+            fs::remove_file(&meta_path).context("Failed to invalidate raw cache metadata")?;
+        }
+
+        Ok(())
+    }
+
     /// Save parsed data to cache
     pub fn save_parsed<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
         let file_path = self.build_parsed_path(key);
@@ -103,6 +137,24 @@ impl Cache {
         self.read_json_opt(&file_path)
     }
 
+    /// Like `load_raw`, but returns `None` instead of the cached value once
+    /// it's older than `max_age` (per its `{id}.meta.json` sidecar), so a
+    /// caller can treat an expired entry the same as a missing one and
+    /// re-fetch. An entry with no sidecar (written before this existed, or
+    /// never written through `write_json`) counts as stale.
+    pub fn load_fresh_raw(&self, id: &str, max_age: Duration) -> Result<Option<Value>> {
+        if self.is_stale_raw(id, max_age) {
+            return Ok(None);
+        }
+        self.load_raw(id)
+    }
+
+    /// Whether the raw entry at `id` is missing, has no recorded metadata,
+    /// or was written longer than `max_age` ago.
+    pub fn is_stale_raw(&self, id: &str, max_age: Duration) -> bool {
+        self.is_stale_at(&self.build_raw_path(id), max_age)
+    }
+
     // --- Helper Methods ---
 
     fn build_raw_path(&self, id: &str) -> PathBuf {
@@ -113,9 +165,28 @@ impl Cache {
         self.parsed_dir.join(format!("{}.json", key))
     }
 
+    fn meta_path_for(path: &Path) -> PathBuf {
+        path.with_extension("meta.json")
+    }
+
     fn write_json<T: Serialize>(&self, path: &Path, data: &T) -> Result<()> {
         let json = serde_json::to_string_pretty(data)?;
-        fs::write(path, json).context("Failed to write cache file")?;
+        fs::write(path, &json).context("Failed to write cache file")?;
+        self.write_meta(path, &json)?;
+        Ok(())
+    }
+
+    fn write_meta(&self, path: &Path, json: &str) -> Result<()> {
+        let mut hasher = DefaultHasher::new();
+        json.hash(&mut hasher);
+
+        let meta = CacheMeta {
+            written_at: Utc::now(),
+            content_hash: hasher.finish(),
+        };
+        let meta_json = serde_json::to_string_pretty(&meta)?;
+        fs::write(Self::meta_path_for(path), meta_json)
+            .context("Failed to write cache metadata")?;
         Ok(())
     }
 
@@ -131,4 +202,22 @@ impl Cache {
                 &json[..json.len().min(200)]))?;
         Ok(Some(data))
     }
+
+    fn is_stale_at(&self, path: &Path, max_age: Duration) -> bool {
+        let meta_path = Self::meta_path_for(path);
+
+        let Ok(meta_json) = fs::read_to_string(&meta_path) else {
+            return true;
+        };
+        let Ok(meta) = serde_json::from_str::<CacheMeta>(&meta_json) else {
+            return true;
+        };
+
+        match Utc::now().signed_duration_since(meta.written_at).to_std() {
+            Ok(age) => age > max_age,
+            // Negative duration means `written_at` is somehow in the
+            // future (clock skew) - treat that as fresh rather than stale.
+            Err(_) => false,
+        }
+    }
 }
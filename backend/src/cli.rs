@@ -17,8 +17,23 @@ pub enum Command {
         #[arg(short, long, default_value_t = 3000)]
         port: u16,
     },
-    /// Fetch new data from CueScore and store it in cache and database
-    Ingest,
+    /// Fetch new data from CueScore (or another source) and store it in
+    /// cache and database
+    Ingest {
+        /// Ignore stored sync state and re-pull every venue from scratch
+        #[arg(long)]
+        full: bool,
+        /// Which provider to pull tournaments from
+        #[arg(long, default_value = "cuescore")]
+        source: String,
+    },
     /// Calculate ratings based on data in the database
     Process,
+    /// Mint a bearer token for the admin routes (e.g. `POST /api/admin/refresh`)
+    MintAdminToken {
+        /// Who the token is issued to; recorded in its `sub` claim and
+        /// logged on every admin action it authorizes.
+        #[arg(long, default_value = "operator")]
+        subject: String,
+    },
 }
@@ -4,6 +4,20 @@ pub struct RatingPeriod {
     pub years: Option<u32>, // None implies "All Time"
 }
 
+/// Which rating engine to run for each period: the batch MM/MLE solver, an
+/// online Elo engine that folds games in one at a time and can be
+/// re-applied incrementally as new games arrive, or Glicko-2.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RatingAlgorithm {
+    BatchMle,
+    OnlineElo { base_k: f64 },
+    /// Glicko-2: like `OnlineElo`, but each player carries a rating
+    /// deviation and volatility alongside the rating itself, updated one
+    /// rating period (games grouped by date) at a time. See
+    /// `rating::glicko2`.
+    Glicko2,
+}
+
 #[derive(Debug, Clone)]
 pub struct RatingSettings {
     pub starter_rating: f64,
@@ -12,11 +26,39 @@ pub struct RatingSettings {
     pub established_games: i32,
     pub convergence_tolerance: f64,
     pub max_iterations: usize,
+    pub algorithm: RatingAlgorithm,
     pub periods: Vec<RatingPeriod>,
+    /// Disciplines to compute a separate `{discipline}_{period}` rating
+    /// table for, alongside (or instead of) the combined one.
+    pub disciplines: Vec<crate::domain::Discipline>,
+    /// Whether to also keep producing the combined, discipline-agnostic
+    /// table (`{period}`) that existed before per-discipline ratings.
+    pub combine_disciplines: bool,
+    /// Rating deviation (RD) at zero elapsed time since a player's last
+    /// game.
+    pub rd_base: f64,
+    /// Upper bound an RD can inflate to however long a player has been
+    /// inactive.
+    pub rd_max: f64,
+    /// `c` in `RD = min(sqrt(rd_base² + c² · Δt_periods), rd_max)` — how
+    /// fast uncertainty grows per elapsed `rd_period_days`.
+    pub decay_const: f64,
+    /// Length in days of one "rating period" for the RD growth formula.
+    pub rd_period_days: f64,
+    /// `s` in `/api/predict`'s `P = 1 / (1 + exp(-(r1 - r2) / s))`. Defaults
+    /// to `100 / ln(2)`, which makes the logistic curve match this crate's
+    /// Fargo-like scale (100 points = 2:1 odds) used everywhere else.
+    pub prediction_scale: f64,
+    /// `scale` in `/api/predict?scale=elo`'s `q = 10^(rating / scale)`.
+    /// Defaults to 200, so a 200-point gap here reads the same as a 400-point
+    /// gap under classic Elo.
+    pub elo_prediction_scale: f64,
 }
 
 impl Default for RatingSettings {
     fn default() -> Self {
+        use crate::domain::Discipline;
+
         Self {
             starter_rating: 500.0,
             virtual_games_weight: 5.0,
@@ -24,6 +66,20 @@ impl Default for RatingSettings {
             established_games: 200,
             convergence_tolerance: 1e-6,
             max_iterations: 100,
+            algorithm: RatingAlgorithm::BatchMle,
+            disciplines: vec![
+                Discipline::EightBall,
+                Discipline::NineBall,
+                Discipline::TenBall,
+                Discipline::StraightPool,
+            ],
+            combine_disciplines: true,
+            rd_base: 50.0,
+            rd_max: 350.0,
+            decay_const: 40.0,
+            rd_period_days: 30.0,
+            prediction_scale: 100.0 / std::f64::consts::LN_2,
+            elo_prediction_scale: 200.0,
             periods: vec![
                 RatingPeriod { name: "all".to_string(), years: None },
                 RatingPeriod { name: "1y".to_string(), years: Some(1) },
@@ -43,6 +99,12 @@ pub struct ScraperSettings {
     pub timeout_secs: u64,
     pub base_url: &'static str,
     pub api_base_url: &'static str,
+    /// How many times to retry a single request after a 429/503 before
+    /// giving up on it.
+    pub max_retries: u32,
+    /// Starting point for exponential backoff when a throttled response
+    /// carries no `Retry-After` header.
+    pub base_backoff_ms: u64,
 }
 
 impl Default for ScraperSettings {
@@ -53,6 +115,62 @@ impl Default for ScraperSettings {
             timeout_secs: 30,
             base_url: "https://cuescore.com",
             api_base_url: "https://api.cuescore.com",
+            max_retries: 3,
+            base_backoff_ms: 500,
+        }
+    }
+}
+
+/// Which provider `IngestionService` pulls tournaments from. See
+/// `fetchers::TournamentSource`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentSourceKind {
+    /// `CueScoreClient` (tournament details) + `VenueScraper` (id discovery).
+    CueScore,
+    /// `ChallongeClient`, covering both in one client.
+    Challonge,
+}
+
+/// Selects and configures the active `TournamentSourceKind`.
+#[derive(Debug, Clone)]
+pub struct SourceSettings {
+    pub kind: TournamentSourceKind,
+    /// Required when `kind` is `Challonge`; read from `CHALLONGE_API_KEY`.
+    pub challonge_api_key: Option<String>,
+}
+
+impl Default for SourceSettings {
+    fn default() -> Self {
+        Self {
+            kind: TournamentSourceKind::CueScore,
+            challonge_api_key: std::env::var("CHALLONGE_API_KEY").ok(),
+        }
+    }
+}
+
+/// Signing config for the `AdminClaims` bearer tokens admin routes require.
+/// See `auth::mint_admin_token` and `auth::AdminClaims`.
+#[derive(Debug, Clone)]
+pub struct AdminSettings {
+    /// HS256 signing secret. Defaults to a fixed value so the server runs
+    /// out of the box, but should be overridden via `ADMIN_JWT_SECRET` for
+    /// any deployment where admin routes are reachable by anyone but the
+    /// operator.
+    pub signing_secret: String,
+    /// Expected `iss` claim; tokens minted for a different issuer are
+    /// rejected even if signed with the right secret.
+    pub issuer: String,
+    /// How long a freshly minted token stays valid for.
+    pub token_ttl_secs: u64,
+}
+
+impl Default for AdminSettings {
+    fn default() -> Self {
+        Self {
+            signing_secret: std::env::var("ADMIN_JWT_SECRET")
+                .unwrap_or_else(|_| "warsaw-pool-ranking-dev-secret".to_string()),
+            issuer: "warsaw-pool-ranking".to_string(),
+            token_ttl_secs: 24 * 60 * 60,
         }
     }
 }
@@ -61,6 +179,8 @@ impl Default for ScraperSettings {
 pub struct AppConfig {
     pub rating: RatingSettings,
     pub scraper: ScraperSettings,
+    pub admin: AdminSettings,
+    pub source: SourceSettings,
 }
 
 impl Default for AppConfig {
@@ -74,6 +194,8 @@ impl AppConfig {
         Self {
             rating: RatingSettings::default(),
             scraper: ScraperSettings::default(),
+            admin: AdminSettings::default(),
+            source: SourceSettings::default(),
         }
     }
 }
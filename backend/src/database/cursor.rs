@@ -0,0 +1,50 @@
+use anyhow::{anyhow, Result};
+
+use super::models::SortColumn;
+
+/// A keyset pagination cursor: the last row's `(sort_value, player_id)` from
+/// the previous page. Re-running the query with
+/// `WHERE (sort_col, player_id) < (cursor.sort_value, cursor.player_id)`
+/// (tuple-ordered to match `sort_order`) seeks straight to the next row
+/// instead of re-scanning and discarding `OFFSET` rows, and doesn't shift
+/// under concurrent inserts/updates the way an offset does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerCursor {
+    pub sort_value: String,
+    pub player_id: i32,
+}
+
+impl PlayerCursor {
+    /// Builds the cursor for the last row of a page, so it can be handed
+    /// back to the caller as the seek point for the next one.
+    pub fn from_row(sort_by: &SortColumn, name: &str, rating: f64, games_played: i32, player_id: i32) -> Self {
+        let sort_value = match sort_by {
+            SortColumn::Name => name.to_string(),
+            SortColumn::Rating => rating.to_string(),
+            SortColumn::GamesPlayed => games_played.to_string(),
+        };
+        Self { sort_value, player_id }
+    }
+
+    /// Encodes the cursor as an opaque token. Callers must treat this as a
+    /// black box and round-trip it through `decode` rather than parsing it.
+    /// Percent-encoding (rather than a new encoding dependency) keeps it safe
+    /// to embed in a query string while staying opaque to callers.
+    pub fn encode(&self) -> String {
+        let raw = format!("{}\x1f{}", self.sort_value, self.player_id);
+        urlencoding::encode(&raw).into_owned()
+    }
+
+    pub fn decode(token: &str) -> Result<Self> {
+        let raw = urlencoding::decode(token).map_err(|_| anyhow!("invalid pagination cursor"))?;
+        let (sort_value, player_id) = raw
+            .rsplit_once('\x1f')
+            .ok_or_else(|| anyhow!("invalid pagination cursor"))?;
+        let player_id = player_id.parse().map_err(|_| anyhow!("invalid pagination cursor"))?;
+
+        Ok(Self {
+            sort_value: sort_value.to_string(),
+            player_id,
+        })
+    }
+}
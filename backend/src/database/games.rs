@@ -1,9 +1,51 @@
 use anyhow::{Context, Result};
 use chrono::NaiveDateTime;
-use rusqlite::params;
+use rusqlite::{params, Row};
 
 use super::connection::DbConn;
-use super::models::Game;
+use super::models::{Game, HeadToHeadMatchRow, MatchResultRow};
+use super::row::{row_extract, DbQueryExt, FromRow};
+
+impl FromRow for Game {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Game {
+            id: row.get(0)?,
+            tournament_id: row.get(1)?,
+            first_player_id: row.get(2)?,
+            second_player_id: row.get(3)?,
+            first_player_score: row.get(4)?,
+            second_player_score: row.get(5)?,
+            date: row.get(6)?,
+            weight: row.get(7)?,
+            created_at: row.get(8)?,
+            discipline: row.get(9)?,
+        })
+    }
+}
+
+impl FromRow for HeadToHeadMatchRow {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(HeadToHeadMatchRow {
+            date: row.get(0)?,
+            tournament_name: row.get(1)?,
+            p1_wins: row.get(2)?,
+            p2_wins: row.get(3)?,
+        })
+    }
+}
+
+impl FromRow for MatchResultRow {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(MatchResultRow {
+            date: row.get(0)?,
+            tournament_name: row.get(1)?,
+            opponent_name: row.get(2)?,
+            opponent_id: row.get(3)?,
+            player_total_score: row.get(4)?,
+            opponent_total_score: row.get(5)?,
+        })
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn insert_game(
@@ -15,8 +57,9 @@ pub fn insert_game(
     second_player_score: i32,
     date: NaiveDateTime,
     weight: f64,
+    discipline: &str,
 ) -> Result<Game> {
-    let sql = "INSERT INTO games (tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) RETURNING id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at";
+    let sql = "INSERT INTO games (tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, discipline) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) RETURNING id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at, discipline";
 
     conn.query_row(
         sql,
@@ -27,50 +70,40 @@ pub fn insert_game(
             first_player_score,
             second_player_score,
             date,
-            weight
+            weight,
+            discipline
         ],
-        parse_game_row,
+        row_extract::<Game>,
     )
     .context("Failed to insert game")
 }
 
-fn parse_game_row(row: &rusqlite::Row) -> rusqlite::Result<Game> {
-    Ok(Game {
-        id: row.get(0)?,
-        tournament_id: row.get(1)?,
-        first_player_id: row.get(2)?,
-        second_player_id: row.get(3)?,
-        first_player_score: row.get(4)?,
-        second_player_score: row.get(5)?,
-        date: row.get(6)?,
-        weight: row.get(7)?,
-        created_at: row.get(8)?,
-    })
-}
-
 pub fn list_all(conn: &mut DbConn) -> Result<Vec<Game>> {
-    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at FROM games";
-
-    let mut stmt = conn.prepare(sql)?;
-    let rows = stmt
-        .query_map([], parse_game_row)?
-        .collect::<rusqlite::Result<Vec<_>>>()?;
-
-    Ok(rows)
+    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at, discipline FROM games";
+    conn.query_all(sql, [])
 }
 
 pub fn list_by_tournament(
     conn: &mut DbConn,
     tournament_id: i32,
 ) -> Result<Vec<Game>> {
-    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at FROM games WHERE tournament_id = ?1";
-
-    let mut stmt = conn.prepare(sql)?;
-    let rows = stmt
-        .query_map(params![tournament_id], parse_game_row)?
-        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at, discipline FROM games WHERE tournament_id = ?1";
+    conn.query_all(sql, params![tournament_id])
+}
 
-    Ok(rows)
+/// Every individual game the two players played against each other,
+/// oldest first. Unlike `get_head_to_head_matches`, which groups frames by
+/// tournament for the `/api/compare` match list, this returns one row per
+/// game — the backing data for `GET /players/:a/history/:b`'s rivalry view.
+pub fn list_games_between(
+    conn: &mut DbConn,
+    player_a_id: i32,
+    player_b_id: i32,
+) -> Result<Vec<Game>> {
+    let sql = "SELECT id, tournament_id, first_player_id, second_player_id, first_player_score, second_player_score, date, weight, created_at, discipline FROM games
+        WHERE (first_player_id = ?1 AND second_player_id = ?2) OR (first_player_id = ?2 AND second_player_id = ?1)
+        ORDER BY date ASC";
+    conn.query_all(sql, params![player_a_id, player_b_id])
 }
 
 pub fn get_head_to_head_matches(
@@ -91,18 +124,7 @@ pub fn get_head_to_head_matches(
         GROUP BY g.tournament_id, t.name, g.date
         ORDER BY g.date DESC
     ";
-
-    let mut stmt = conn.prepare(sql)?;
-    let rows = stmt.query_map(params![player1_id, player2_id], |row| {
-        Ok(super::models::HeadToHeadMatchRow {
-            date: row.get(0)?,
-            tournament_name: row.get(1)?,
-            p1_wins: row.get(2)?,
-            p2_wins: row.get(3)?,
-        })
-    })?.collect::<rusqlite::Result<Vec<_>>>()?;
-
-    Ok(rows)
+    conn.query_all(sql, params![player1_id, player2_id])
 }
 
 pub fn count_matches_played_for_player(
@@ -142,18 +164,5 @@ pub fn get_player_last_matches(
         ORDER BY g.date DESC
         LIMIT ?2
     ";
-
-    let mut stmt = conn.prepare(sql)?;
-    let rows = stmt.query_map(params![player_id, limit as i64], |row| {
-        Ok(super::models::MatchResultRow {
-            date: row.get(0)?,
-            tournament_name: row.get(1)?,
-            opponent_name: row.get(2)?,
-            opponent_id: row.get(3)?,
-            player_total_score: row.get(4)?,
-            opponent_total_score: row.get(5)?,
-        })
-    })?.collect::<rusqlite::Result<Vec<_>>>()?;
-
-    Ok(rows)
+    conn.query_all(sql, params![player_id, limit as i64])
 }
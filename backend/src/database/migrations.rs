@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use rusqlite::params;
+
+use super::connection::DbConn;
+
+/// One schema change, applied exactly once, in order. Unlike the old
+/// `reset_database`, which re-ran the entire schema from scratch and wiped
+/// whatever was there, each migration only ever moves the schema forward by
+/// one step, so existing data survives a deploy.
+struct Migration {
+    version: i32,
+    description: &'static str,
+    sql: &'static str,
+}
+
+/// Ordered schema history, oldest first. Append new entries here as the
+/// schema evolves — never edit, renumber, or remove one that has already
+/// shipped, since a database that already recorded it in
+/// `schema_migrations` must never see it run twice.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial schema",
+        sql: include_str!("schema.sql"),
+    },
+    Migration {
+        version: 2,
+        description: "Add rating_deviation to ratings",
+        sql: "ALTER TABLE ratings ADD COLUMN rating_deviation REAL NOT NULL DEFAULT 350.0;",
+    },
+    Migration {
+        version: 3,
+        description: "Add the pairwise advantage network table",
+        sql: "
+            CREATE TABLE IF NOT EXISTS network (
+                player_a INTEGER NOT NULL,
+                player_b INTEGER NOT NULL,
+                sets_a INTEGER NOT NULL,
+                sets_b INTEGER NOT NULL,
+                advantage REAL NOT NULL,
+                PRIMARY KEY (player_a, player_b),
+                CHECK (player_a < player_b)
+            );
+        ",
+    },
+    Migration {
+        version: 4,
+        description: "Add volatility to ratings (Glicko-2)",
+        sql: "ALTER TABLE ratings ADD COLUMN volatility REAL;",
+    },
+    Migration {
+        version: 5,
+        description: "Add per-tournament sync state",
+        sql: "
+            CREATE TABLE IF NOT EXISTS tournament_sync_state (
+                tournament_id INTEGER PRIMARY KEY,
+                last_sync TEXT NOT NULL
+            );
+        ",
+    },
+    Migration {
+        version: 6,
+        description: "Add per-venue sync state",
+        sql: "
+            CREATE TABLE IF NOT EXISTS sync_state (
+                venue_id INTEGER PRIMARY KEY,
+                last_sync TEXT NOT NULL
+            );
+        ",
+    },
+    Migration {
+        version: 7,
+        description: "Add rating_history for per-period rating snapshots",
+        sql: "
+            CREATE TABLE IF NOT EXISTS rating_history (
+                player_id INTEGER NOT NULL,
+                rating_type TEXT NOT NULL,
+                period_date TEXT NOT NULL,
+                rating REAL NOT NULL,
+                games_played INTEGER NOT NULL
+            );
+        ",
+    },
+];
+
+/// Brings `conn`'s schema up to the latest version, applying only the
+/// migrations newer than what `schema_migrations` already records. Each
+/// migration runs inside its own transaction (via `execute_batch`, which —
+/// unlike splitting `schema.sql` on `;` — understands string literals and
+/// multi-statement triggers) so a failing step can't leave the schema
+/// half-applied.
+pub fn migrate(conn: &mut DbConn) -> Result<()> {
+    ensure_migrations_table(conn)?;
+    let current_version = current_version(conn)?;
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current_version) {
+        apply_migration(conn, migration)?;
+    }
+
+    Ok(())
+}
+
+fn ensure_migrations_table(conn: &mut DbConn) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )",
+        [],
+    )
+    .context("Failed to create schema_migrations table")?;
+    Ok(())
+}
+
+fn current_version(conn: &mut DbConn) -> Result<i32> {
+    conn.query_row("SELECT COALESCE(MAX(version), 0) FROM schema_migrations", [], |row| {
+        row.get(0)
+    })
+    .context("Failed to read current schema version")
+}
+
+fn apply_migration(conn: &mut DbConn, migration: &Migration) -> Result<()> {
+    let tx = conn
+        .transaction()
+        .context("Failed to start migration transaction")?;
+
+    tx.execute_batch(migration.sql).with_context(|| {
+        format!(
+            "Failed to apply migration {} ({})",
+            migration.version, migration.description
+        )
+    })?;
+    tx.execute(
+        "INSERT INTO schema_migrations (version) VALUES (?1)",
+        params![migration.version],
+    )
+    .with_context(|| format!("Failed to record migration {}", migration.version))?;
+
+    tx.commit().context("Failed to commit migration transaction")?;
+
+    log::info!("Applied migration {}: {}", migration.version, migration.description);
+    Ok(())
+}
@@ -1,9 +1,15 @@
 pub mod connection;
+pub mod cursor;
 pub mod games;
+pub mod migrations;
 pub mod models;
+pub mod network;
 pub mod players;
+pub mod rating_history;
 pub mod ratings;
-pub mod setup;
+pub mod row;
+pub mod sync_state;
+pub mod tournament_sync;
 pub mod tournaments;
 
 pub use connection::{create_pool, get_connection, DbConn, DbPool};
@@ -1,5 +1,7 @@
 use chrono::NaiveDateTime;
 
+use crate::domain::Discipline;
+
 #[derive(Debug, Clone)]
 pub struct Player {
     pub id: i32,
@@ -19,6 +21,7 @@ pub struct Tournament {
     pub start_date: NaiveDateTime,
     pub end_date: Option<NaiveDateTime>,
     pub created_at: Option<NaiveDateTime>,
+    pub discipline: String,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +35,7 @@ pub struct Game {
     pub date: NaiveDateTime,
     pub weight: f64,
     pub created_at: Option<NaiveDateTime>,
+    pub discipline: String,
 }
 
 #[derive(Debug, Clone)]
@@ -42,10 +46,26 @@ pub struct DbRating {
     pub rating: f64,
     pub games_played: i32,
     pub confidence_level: String,
+    pub rating_deviation: f64,
+    /// Glicko-2 volatility; `NULL`/`None` for every other `rating_type`.
+    pub volatility: Option<f64>,
     pub calculated_at: NaiveDateTime,
     pub created_at: Option<NaiveDateTime>,
 }
 
+/// A persisted edge of the pairwise advantage network: aggregated set
+/// counts between two players who have met, plus the log-odds advantage
+/// derived from them. Stored with `player_a < player_b` (the table's
+/// `CHECK` invariant) so each pair has exactly one row.
+#[derive(Debug, Clone)]
+pub struct NetworkEdge {
+    pub player_a: i32,
+    pub player_b: i32,
+    pub sets_a: i32,
+    pub sets_b: i32,
+    pub advantage: f64,
+}
+
 // DTOs for joined queries
 #[derive(Debug, Clone)]
 pub struct PlayerWithRating {
@@ -56,6 +76,8 @@ pub struct PlayerWithRating {
     pub rating: f64,
     pub games_played: i32,
     pub confidence_level: String,
+    pub rating_deviation: f64,
+    pub volatility: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -76,10 +98,25 @@ pub struct PlayerFilter {
     pub name_contains: Option<String>,
     pub min_games: Option<i32>,
     pub rating_type: String,
+    /// Scopes the ranking to a single discipline's rating table
+    /// (`{discipline}_{rating_type}`) instead of the combined one.
+    pub discipline: Option<Discipline>,
     pub sort_by: SortColumn,
     pub sort_order: SortOrder,
     pub limit: usize,
     pub offset: usize,
+    /// When set, seeks past this cursor's `(sort_value, player_id)` with a
+    /// keyset `WHERE` clause instead of skipping `offset` rows. Takes
+    /// precedence over `offset` when both are present.
+    pub cursor: Option<super::cursor::PlayerCursor>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RatingHistoryEntry {
+    pub player_id: i32,
+    pub period_date: NaiveDateTime,
+    pub rating: f64,
+    pub games_played: i32,
 }
 
 #[derive(Debug, Clone)]
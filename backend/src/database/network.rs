@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use rusqlite::params;
+
+use crate::advantage_graph::log_odds_advantage;
+
+use super::connection::DbConn;
+use super::models::{Game, NetworkEdge};
+
+/// Upserts the aggregated set counts for the ordered pair `(player_a,
+/// player_b)`, recomputing the stored advantage from the new totals.
+/// `player_a` must be less than `player_b` — the table's `CHECK` invariant —
+/// so each unordered pair has exactly one row.
+pub fn upsert_edge(
+    conn: &mut DbConn,
+    player_a: i32,
+    player_b: i32,
+    sets_a: i32,
+    sets_b: i32,
+) -> Result<NetworkEdge> {
+    anyhow::ensure!(player_a < player_b, "network edges must be stored with player_a < player_b");
+
+    let advantage = log_odds_advantage(sets_a as f64, sets_b as f64);
+    let sql = "
+        INSERT INTO network (player_a, player_b, sets_a, sets_b, advantage)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT(player_a, player_b) DO UPDATE SET
+            sets_a = excluded.sets_a,
+            sets_b = excluded.sets_b,
+            advantage = excluded.advantage
+        RETURNING player_a, player_b, sets_a, sets_b, advantage
+    ";
+
+    conn.query_row(sql, params![player_a, player_b, sets_a, sets_b, advantage], parse_network_row)
+        .context("Failed to upsert network edge")
+}
+
+fn parse_network_row(row: &rusqlite::Row) -> rusqlite::Result<NetworkEdge> {
+    Ok(NetworkEdge {
+        player_a: row.get(0)?,
+        player_b: row.get(1)?,
+        sets_a: row.get(2)?,
+        sets_b: row.get(3)?,
+        advantage: row.get(4)?,
+    })
+}
+
+/// Every persisted edge, for loading into an `AdvantageGraph` without
+/// scanning the full games table.
+pub fn list_all(conn: &mut DbConn) -> Result<Vec<NetworkEdge>> {
+    let sql = "SELECT player_a, player_b, sets_a, sets_b, advantage FROM network";
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map([], parse_network_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(rows)
+}
+
+/// Recomputes every edge from the complete game history and upserts them,
+/// so the `network` table stays in sync after both a full rebuild and an
+/// incremental ingest. Returns the number of pairs written.
+pub fn rebuild_from_games(conn: &mut DbConn, games: &[Game]) -> Result<usize> {
+    let mut sets: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+
+    for game in games {
+        let (player_a, player_b, sets_a, sets_b) = if game.first_player_id < game.second_player_id {
+            (game.first_player_id, game.second_player_id, game.first_player_score, game.second_player_score)
+        } else {
+            (game.second_player_id, game.first_player_id, game.second_player_score, game.first_player_score)
+        };
+
+        let entry = sets.entry((player_a, player_b)).or_insert((0, 0));
+        entry.0 += sets_a;
+        entry.1 += sets_b;
+    }
+
+    for (&(player_a, player_b), &(sets_a, sets_b)) in &sets {
+        upsert_edge(conn, player_a, player_b, sets_a, sets_b)?;
+    }
+
+    Ok(sets.len())
+}
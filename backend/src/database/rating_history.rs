@@ -0,0 +1,64 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use rusqlite::params;
+
+use super::connection::DbConn;
+use super::models::RatingHistoryEntry;
+
+pub fn insert_snapshot(
+    conn: &mut DbConn,
+    player_id: i32,
+    rating_type: &str,
+    period_date: NaiveDateTime,
+    rating: f64,
+    games_played: i32,
+) -> Result<()> {
+    let sql = "INSERT INTO rating_history (player_id, rating_type, period_date, rating, games_played) VALUES (?1, ?2, ?3, ?4, ?5)";
+
+    conn.execute(sql, params![player_id, rating_type, period_date, rating, games_played])
+        .context("Failed to insert rating history snapshot")?;
+
+    Ok(())
+}
+
+pub fn list_for_player(
+    conn: &mut DbConn,
+    player_id: i32,
+    rating_type: &str,
+) -> Result<Vec<RatingHistoryEntry>> {
+    let sql = "SELECT player_id, period_date, rating, games_played FROM rating_history WHERE player_id = ?1 AND rating_type = ?2 ORDER BY period_date ASC";
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map(params![player_id, rating_type], |row| {
+            Ok(RatingHistoryEntry {
+                player_id: row.get(0)?,
+                period_date: row.get(1)?,
+                rating: row.get(2)?,
+                games_played: row.get(3)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(rows)
+}
+
+/// Change in rating between the two most recent snapshots, or `None` if
+/// fewer than two snapshots exist yet.
+pub fn delta_since_previous_period(
+    conn: &mut DbConn,
+    player_id: i32,
+    rating_type: &str,
+) -> Result<Option<f64>> {
+    let sql = "SELECT rating FROM rating_history WHERE player_id = ?1 AND rating_type = ?2 ORDER BY period_date DESC LIMIT 2";
+
+    let mut stmt = conn.prepare(sql)?;
+    let ratings = stmt
+        .query_map(params![player_id, rating_type], |row| row.get::<_, f64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(match ratings.as_slice() {
+        [latest, previous, ..] => Some(latest - previous),
+        _ => None,
+    })
+}
@@ -1,10 +1,63 @@
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use chrono::NaiveDateTime;
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{params, OptionalExtension, Row};
 
 use super::connection::DbConn;
+use super::cursor::PlayerCursor;
 use super::models::{DbRating, PlayerWithRating, PlayerFilter, SortColumn, SortOrder};
+use super::row::{row_extract, DbQueryExt, FromRow};
+
+impl FromRow for DbRating {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(DbRating {
+            id: row.get(0)?,
+            player_id: row.get(1)?,
+            rating_type: row.get(2)?,
+            rating: row.get(3)?,
+            games_played: row.get(4)?,
+            confidence_level: row.get(5)?,
+            rating_deviation: row.get(6)?,
+            volatility: row.get(7)?,
+            calculated_at: row.get(8)?,
+            created_at: row.get(9)?,
+        })
+    }
+}
+
+/// Polish letters that carry diacritics, paired with their ASCII fold, so
+/// "Kowalski" matches a stored "Kowalśki" and vice versa. Applied to both the
+/// search term and the `name` column before comparing. Upper-case forms are
+/// listed too because SQLite's `LOWER` only lower-cases ASCII letters.
+const DIACRITIC_FOLDS: &[(&str, &str)] = &[
+    ("ą", "a"), ("ć", "c"), ("ę", "e"), ("ł", "l"), ("ń", "n"),
+    ("ó", "o"), ("ś", "s"), ("ź", "z"), ("ż", "z"),
+    ("Ą", "a"), ("Ć", "c"), ("Ę", "e"), ("Ł", "l"), ("Ń", "n"),
+    ("Ó", "o"), ("Ś", "s"), ("Ź", "z"), ("Ż", "z"),
+];
+
+/// Lowercases and folds Polish diacritics, matching `normalized_name_sql`.
+fn normalize_name(name: &str) -> String {
+    let mut folded = name.to_lowercase();
+    for (accented, plain) in DIACRITIC_FOLDS {
+        folded = folded.replace(accented, plain);
+    }
+    folded
+}
+
+/// SQLite has no built-in Unicode-aware diacritic folding, so we fold the
+/// column in SQL with the same substitutions `normalize_name` applies to the
+/// search term, keeping the two sides comparable.
+fn normalized_name_sql(column: &str) -> String {
+    let mut expr = format!("LOWER({})", column);
+    for (accented, plain) in DIACRITIC_FOLDS {
+        expr = format!("REPLACE({}, '{}', '{}')", expr, accented, plain);
+    }
+    expr
+}
 
+#[allow(clippy::too_many_arguments)]
 pub fn insert_rating(
     conn: &mut DbConn,
     player_id: i32,
@@ -12,44 +65,27 @@ pub fn insert_rating(
     rating: f64,
     games_played: i32,
     confidence_level: &str,
+    rating_deviation: f64,
+    volatility: Option<f64>,
     calculated_at: NaiveDateTime,
 ) -> Result<DbRating> {
-    let sql = "INSERT INTO ratings (player_id, rating_type, rating, games_played, confidence_level, calculated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6) RETURNING id, player_id, rating_type, rating, games_played, confidence_level, calculated_at, created_at";
+    let sql = "INSERT INTO ratings (player_id, rating_type, rating, games_played, confidence_level, rating_deviation, volatility, calculated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) RETURNING id, player_id, rating_type, rating, games_played, confidence_level, rating_deviation, volatility, calculated_at, created_at";
 
     conn.query_row(
         sql,
-        params![player_id, rating_type, rating, games_played, confidence_level, calculated_at],
-        parse_db_rating_row,
+        params![player_id, rating_type, rating, games_played, confidence_level, rating_deviation, volatility, calculated_at],
+        row_extract::<DbRating>,
     )
     .context("Failed to insert rating")
 }
 
-fn parse_db_rating_row(row: &rusqlite::Row) -> rusqlite::Result<DbRating> {
-    Ok(DbRating {
-        id: row.get(0)?,
-        player_id: row.get(1)?,
-        rating_type: row.get(2)?,
-        rating: row.get(3)?,
-        games_played: row.get(4)?,
-        confidence_level: row.get(5)?,
-        calculated_at: row.get(6)?,
-        created_at: row.get(7)?,
-    })
-}
-
 pub fn list_by_player(
     conn: &mut DbConn,
     player_id: i32,
     rating_type: &str,
 ) -> Result<Vec<DbRating>> {
-    let sql = "SELECT id, player_id, rating_type, rating, games_played, confidence_level, calculated_at, created_at FROM ratings WHERE player_id = ?1 AND rating_type = ?2 ORDER BY calculated_at DESC";
-
-    let mut stmt = conn.prepare(sql)?;
-    let rows = stmt
-        .query_map(params![player_id, rating_type], parse_db_rating_row)?
-        .collect::<rusqlite::Result<Vec<_>>>()?;
-
-    Ok(rows)
+    let sql = "SELECT id, player_id, rating_type, rating, games_played, confidence_level, rating_deviation, volatility, calculated_at, created_at FROM ratings WHERE player_id = ?1 AND rating_type = ?2 ORDER BY calculated_at DESC";
+    conn.query_all(sql, params![player_id, rating_type])
 }
 
 pub fn get_latest_for_player(
@@ -57,11 +93,8 @@ pub fn get_latest_for_player(
     player_id: i32,
     rating_type: &str,
 ) -> Result<Option<DbRating>> {
-    let sql = "SELECT id, player_id, rating_type, rating, games_played, confidence_level, calculated_at, created_at FROM ratings WHERE player_id = ?1 AND rating_type = ?2 ORDER BY calculated_at DESC LIMIT 1";
-
-    conn.query_row(sql, params![player_id, rating_type], parse_db_rating_row)
-        .optional()
-        .context("Failed to get latest rating for player")
+    let sql = "SELECT id, player_id, rating_type, rating, games_played, confidence_level, rating_deviation, volatility, calculated_at, created_at FROM ratings WHERE player_id = ?1 AND rating_type = ?2 ORDER BY calculated_at DESC LIMIT 1";
+    conn.query_opt(sql, params![player_id, rating_type])
 }
 
 pub fn get_player_rating_detail(
@@ -70,9 +103,9 @@ pub fn get_player_rating_detail(
     rating_type: &str,
 ) -> Result<Option<PlayerWithRating>> {
     let sql = "
-        SELECT p.id, p.cuescore_id, p.name, r.rating, r.games_played, r.confidence_level 
-        FROM players p 
-        JOIN ratings r ON p.id = r.player_id 
+        SELECT p.id, p.cuescore_id, p.name, r.rating, r.games_played, r.confidence_level, r.rating_deviation, r.volatility
+        FROM players p
+        JOIN ratings r ON p.id = r.player_id
         WHERE p.id = ?1 AND r.rating_type = ?2
     ";
 
@@ -84,43 +117,92 @@ pub fn get_player_rating_detail(
             rating: row.get(3)?,
             games_played: row.get(4)?,
             confidence_level: row.get(5)?,
+            rating_deviation: row.get(6)?,
+            volatility: row.get(7)?,
         })
     }).optional().context("Failed to get player rating detail")
 }
 
-pub fn list_ranked_players(
+/// The most recent rating for every player under `rating_type`, keyed by
+/// cuescore id rather than the internal DB id, so the incremental processing
+/// path can warm-start the MM solver from a previous run instead of
+/// refitting every player from gamma=1.
+pub fn list_latest_ratings_by_cuescore_id(
     conn: &mut DbConn,
-    filter: &PlayerFilter,
-) -> Result<(Vec<PlayerWithRating>, usize)> {
+    rating_type: &str,
+) -> Result<HashMap<i64, f64>> {
+    let sql = "
+        SELECT p.cuescore_id, r.rating
+        FROM ratings r
+        JOIN players p ON p.id = r.player_id
+        WHERE r.rating_type = ?1
+          AND r.calculated_at = (SELECT MAX(calculated_at) FROM ratings WHERE rating_type = ?1)
+    ";
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map(params![rating_type], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(i64, f64)>>>()?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Resolves the filter to the actual `rating_type` stored on disk: when a
+/// discipline is set this looks up the per-discipline table
+/// (`{discipline}_{rating_type}`) instead of the combined one.
+fn effective_rating_type(filter: &PlayerFilter) -> String {
+    match &filter.discipline {
+        Some(discipline) => format!("{}_{}", discipline.as_str(), filter.rating_type),
+        None => filter.rating_type.clone(),
+    }
+}
+
+/// Builds the `WHERE` clauses and bound params shared by the count and the
+/// page query. Returned fresh on each call since `Box<dyn ToSql>` isn't
+/// `Clone` — the count query never needs the keyset seek clause, so callers
+/// that do append it themselves.
+fn base_filter_sql(filter: &PlayerFilter) -> (Vec<String>, Vec<Box<dyn rusqlite::ToSql>>) {
     let mut where_clauses = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
-    // Mandatory filters
-    where_clauses.push("r.rating_type = ?");
-    params.push(Box::new(filter.rating_type.clone()));
+    where_clauses.push("r.rating_type = ?".to_string());
+    params.push(Box::new(effective_rating_type(filter)));
 
     if let Some(min_games) = filter.min_games {
-        where_clauses.push("r.games_played >= ?");
+        where_clauses.push("r.games_played >= ?".to_string());
         params.push(Box::new(min_games));
     }
 
     if let Some(name_filter) = &filter.name_contains {
-        where_clauses.push("p.name LIKE ?");
-        params.push(Box::new(format!("%{}%", name_filter)));
+        where_clauses.push(format!("{} LIKE ?", normalized_name_sql("p.name")));
+        params.push(Box::new(format!("%{}%", normalize_name(name_filter))));
     }
 
-    let where_sql = if where_clauses.is_empty() {
+    (where_clauses, params)
+}
+
+/// Ranked player listing, keyset-paginated when `filter.cursor` is set.
+/// Returns the page of rows, the total matching the filters (ignoring the
+/// cursor/offset), and an opaque cursor for the next page when more rows
+/// remain.
+pub fn list_ranked_players(
+    conn: &mut DbConn,
+    filter: &PlayerFilter,
+) -> Result<(Vec<PlayerWithRating>, usize, Option<String>)> {
+    // Count (over the filters only — a cursor only affects which page of an
+    // otherwise-unchanged result set is returned).
+    let (count_where_clauses, count_params) = base_filter_sql(filter);
+    let count_where_sql = if count_where_clauses.is_empty() {
         String::new()
     } else {
-        format!("WHERE {}", where_clauses.join(" AND "))
+        format!("WHERE {}", count_where_clauses.join(" AND "))
     };
-
-    // Count
     let count_sql = format!(
         "SELECT COUNT(*) FROM players p JOIN ratings r ON p.id = r.player_id {}",
-        where_sql
+        count_where_sql
     );
-    let total: usize = conn.query_row(&count_sql, rusqlite::params_from_iter(params.iter()), |row| row.get(0))?;
+    let total: usize =
+        conn.query_row(&count_sql, rusqlite::params_from_iter(count_params.iter()), |row| row.get(0))?;
 
     // Sort
     let sort_col = match filter.sort_by {
@@ -133,21 +215,49 @@ pub fn list_ranked_players(
         SortOrder::Desc => "DESC",
     };
 
+    // Keyset seek: strictly past the cursor's (sort_value, player_id) in
+    // sort order, so a row that ties on sort_col is still ordered (and never
+    // repeated/skipped) by the player_id tiebreaker.
+    let (mut seek_clauses, mut seek_params) = base_filter_sql(filter);
+    if let Some(cursor) = &filter.cursor {
+        let seek_op = match filter.sort_order {
+            SortOrder::Asc => ">",
+            SortOrder::Desc => "<",
+        };
+        seek_clauses.push(format!("({}, p.id) {} (?, ?)", sort_col, seek_op));
+        match filter.sort_by {
+            SortColumn::Name => seek_params.push(Box::new(cursor.sort_value.clone())),
+            SortColumn::Rating => seek_params.push(Box::new(cursor.sort_value.parse::<f64>()?)),
+            SortColumn::GamesPlayed => seek_params.push(Box::new(cursor.sort_value.parse::<i32>()?)),
+        }
+        seek_params.push(Box::new(cursor.player_id));
+    }
+    let seek_where_sql = if seek_clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", seek_clauses.join(" AND "))
+    };
+
     let sql = format!(
-        "SELECT p.id, p.cuescore_id, p.name, r.rating, r.games_played, r.confidence_level 
-         FROM players p 
-         JOIN ratings r ON p.id = r.player_id 
-         {} 
-         ORDER BY {} {} 
+        "SELECT p.id, p.cuescore_id, p.name, r.rating, r.games_played, r.confidence_level, r.rating_deviation, r.volatility
+         FROM players p
+         JOIN ratings r ON p.id = r.player_id
+         {}
+         ORDER BY {} {}, p.id {}
          LIMIT ? OFFSET ?",
-        where_sql, sort_col, sort_dir
+        seek_where_sql, sort_col, sort_dir, sort_dir
     );
 
-    params.push(Box::new(filter.limit as i64));
-    params.push(Box::new(filter.offset as i64));
+    // When a cursor is present it already seeks to the right spot, so the
+    // offset is always 0; `offset` only still applies to un-seeked requests
+    // (e.g. a client paging by page number instead of cursor).
+    // One row past the page is fetched only to tell whether another page
+    // follows; it is dropped before returning.
+    seek_params.push(Box::new(filter.limit as i64 + 1));
+    seek_params.push(Box::new(if filter.cursor.is_some() { 0 } else { filter.offset as i64 }));
 
     let mut stmt = conn.prepare(&sql)?;
-    let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
+    let mut rows = stmt.query_map(rusqlite::params_from_iter(seek_params.iter()), |row| {
         Ok(PlayerWithRating {
             player_id: row.get(0)?,
             cuescore_id: row.get(1)?,
@@ -155,8 +265,22 @@ pub fn list_ranked_players(
             rating: row.get(3)?,
             games_played: row.get(4)?,
             confidence_level: row.get(5)?,
+            rating_deviation: row.get(6)?,
+            volatility: row.get(7)?,
         })
     })?.collect::<rusqlite::Result<Vec<_>>>()?;
 
-    Ok((rows, total))
+    let has_more = rows.len() > filter.limit;
+    rows.truncate(filter.limit);
+
+    let next_cursor = if has_more {
+        rows.last().map(|last| {
+            PlayerCursor::from_row(&filter.sort_by, &last.name, last.rating, last.games_played, last.player_id)
+                .encode()
+        })
+    } else {
+        None
+    };
+
+    Ok((rows, total, next_cursor))
 }
\ No newline at end of file
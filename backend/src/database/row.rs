@@ -0,0 +1,57 @@
+use anyhow::{Context, Result};
+use rusqlite::{OptionalExtension, Params, Row};
+
+use super::connection::DbConn;
+
+/// Maps a single SQL result row onto `Self`, column-by-column in the same
+/// order the query's `SELECT` lists them. Replaces the old pattern of every
+/// query function hand-writing its own positional `parse_*_row` closure,
+/// which silently drifted out of sync whenever a column was added.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> rusqlite::Result<Self>;
+}
+
+/// Adapts `FromRow::from_row` to the `fn(&Row) -> rusqlite::Result<T>`
+/// shape `query_map`/`query_row` expect.
+pub fn row_extract<T: FromRow>(row: &Row) -> rusqlite::Result<T> {
+    T::from_row(row)
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($idx:tt => $t:ident),+) => {
+        impl<$($t: rusqlite::types::FromSql),+> FromRow for ($($t,)+) {
+            fn from_row(row: &Row) -> rusqlite::Result<Self> {
+                Ok(($(row.get($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => A);
+impl_from_row_for_tuple!(0 => A, 1 => B);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C);
+
+/// Small query helpers built on `FromRow`, so a query function only needs to
+/// provide SQL + params and name the type it wants back.
+pub trait DbQueryExt {
+    fn query_all<T: FromRow, P: Params>(&self, sql: &str, params: P) -> Result<Vec<T>>;
+    fn query_opt<T: FromRow, P: Params>(&self, sql: &str, params: P) -> Result<Option<T>>;
+}
+
+impl DbQueryExt for DbConn {
+    fn query_all<T: FromRow, P: Params>(&self, sql: &str, params: P) -> Result<Vec<T>> {
+        let mut stmt = self.prepare(sql).context("Failed to prepare query")?;
+        let rows = stmt
+            .query_map(params, row_extract::<T>)
+            .context("Failed to run query")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to read query results")?;
+        Ok(rows)
+    }
+
+    fn query_opt<T: FromRow, P: Params>(&self, sql: &str, params: P) -> Result<Option<T>> {
+        self.query_row(sql, params, row_extract::<T>)
+            .optional()
+            .context("Failed to run query")
+    }
+}
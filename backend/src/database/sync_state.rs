@@ -0,0 +1,30 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use rusqlite::{params, OptionalExtension};
+
+use super::connection::DbPool;
+
+pub fn get_last_sync(pool: &DbPool, venue_id: i64) -> Result<Option<NaiveDateTime>> {
+    let conn = pool.get().context("Failed to get database connection from pool")?;
+
+    conn.query_row(
+        "SELECT last_sync FROM sync_state WHERE venue_id = ?1",
+        params![venue_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to read sync state")
+}
+
+pub fn update_last_sync(pool: &DbPool, venue_id: i64, ts: NaiveDateTime) -> Result<()> {
+    let conn = pool.get().context("Failed to get database connection from pool")?;
+
+    conn.execute(
+        "INSERT INTO sync_state (venue_id, last_sync) VALUES (?1, ?2)
+         ON CONFLICT(venue_id) DO UPDATE SET last_sync = excluded.last_sync",
+        params![venue_id, ts],
+    )
+    .context("Failed to update sync state")?;
+
+    Ok(())
+}
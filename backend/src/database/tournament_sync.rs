@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use rusqlite::{params, OptionalExtension};
+
+use super::connection::DbPool;
+
+/// Per-tournament analogue of `sync_state`: when we last confirmed a
+/// tournament's data was up to date, so `IngestionService` can tell a
+/// long-finished tournament (safe to leave cached) apart from one that's
+/// still ongoing or only just wrapped up (needs a fresh fetch) instead of
+/// re-scraping or never-refreshing an entire venue's history wholesale.
+pub fn get_last_sync(pool: &DbPool, tournament_id: i64) -> Result<Option<NaiveDateTime>> {
+    let conn = pool.get().context("Failed to get database connection from pool")?;
+
+    conn.query_row(
+        "SELECT last_sync FROM tournament_sync_state WHERE tournament_id = ?1",
+        params![tournament_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .context("Failed to read tournament sync state")
+}
+
+pub fn update_last_sync(pool: &DbPool, tournament_id: i64, ts: NaiveDateTime) -> Result<()> {
+    let conn = pool.get().context("Failed to get database connection from pool")?;
+
+    conn.execute(
+        "INSERT INTO tournament_sync_state (tournament_id, last_sync) VALUES (?1, ?2)
+         ON CONFLICT(tournament_id) DO UPDATE SET last_sync = excluded.last_sync",
+        params![tournament_id, ts],
+    )
+    .context("Failed to update tournament sync state")?;
+
+    Ok(())
+}
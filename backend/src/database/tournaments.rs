@@ -5,6 +5,7 @@ use rusqlite::{params, OptionalExtension};
 use super::connection::DbConn;
 use super::models::Tournament;
 
+#[allow(clippy::too_many_arguments)]
 pub fn upsert_tournament(
     conn: &mut DbConn,
     cuescore_id: i64,
@@ -13,6 +14,7 @@ pub fn upsert_tournament(
     venue_name: &str,
     start_date: NaiveDateTime,
     end_date: Option<NaiveDateTime>,
+    discipline: &str,
 ) -> Result<Tournament> {
     if let Some(existing) = find_by_cuescore_id(conn, cuescore_id)? {
         return Ok(existing);
@@ -26,6 +28,7 @@ pub fn upsert_tournament(
         venue_name,
         start_date,
         end_date,
+        discipline,
     )
 }
 
@@ -33,13 +36,14 @@ fn find_by_cuescore_id(
     conn: &mut DbConn,
     cuescore_id: i64,
 ) -> Result<Option<Tournament>> {
-    let sql = "SELECT id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at FROM tournaments WHERE cuescore_id = ?1";
+    let sql = "SELECT id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at, discipline FROM tournaments WHERE cuescore_id = ?1";
 
     conn.query_row(sql, params![cuescore_id], parse_tournament_row)
         .optional()
         .context("Failed to query tournament by cuescore_id")
 }
 
+#[allow(clippy::too_many_arguments)]
 fn insert_new_tournament(
     conn: &mut DbConn,
     cuescore_id: i64,
@@ -48,12 +52,13 @@ fn insert_new_tournament(
     venue_name: &str,
     start_date: NaiveDateTime,
     end_date: Option<NaiveDateTime>,
+    discipline: &str,
 ) -> Result<Tournament> {
-    let sql = "INSERT INTO tournaments (cuescore_id, name, venue_id, venue_name, start_date, end_date) VALUES (?1, ?2, ?3, ?4, ?5, ?6) RETURNING id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at";
+    let sql = "INSERT INTO tournaments (cuescore_id, name, venue_id, venue_name, start_date, end_date, discipline) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) RETURNING id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at, discipline";
 
     conn.query_row(
         sql,
-        params![cuescore_id, name, venue_id, venue_name, start_date, end_date],
+        params![cuescore_id, name, venue_id, venue_name, start_date, end_date, discipline],
         parse_tournament_row,
     )
     .context("Failed to insert new tournament")
@@ -69,11 +74,26 @@ fn parse_tournament_row(row: &rusqlite::Row) -> rusqlite::Result<Tournament> {
         start_date: row.get(5)?,
         end_date: row.get(6)?,
         created_at: row.get(7)?,
+        discipline: row.get(8)?,
     })
 }
 
+/// Cuescore ids of every tournament already persisted, used by the
+/// incremental processing path to diff the cache against what's on disk
+/// instead of re-ingesting everything on each run.
+pub fn list_all_cuescore_ids(conn: &mut DbConn) -> Result<Vec<i64>> {
+    let sql = "SELECT cuescore_id FROM tournaments";
+
+    let mut stmt = conn.prepare(sql)?;
+    let ids = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(ids)
+}
+
 pub fn find_by_id(conn: &mut DbConn, id: i32) -> Result<Option<Tournament>> {
-    let sql = "SELECT id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at FROM tournaments WHERE id = ?1";
+    let sql = "SELECT id, cuescore_id, name, venue_id, venue_name, start_date, end_date, created_at, discipline FROM tournaments WHERE id = ?1";
 
     conn.query_row(sql, params![id], parse_tournament_row)
         .optional()
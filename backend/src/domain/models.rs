@@ -10,6 +10,70 @@ pub struct Tournament {
     pub venue_name: String,
     pub start_date: DateTime<Utc>,
     pub end_date: Option<DateTime<Utc>>,
+    pub discipline: Discipline,
+}
+
+/// Which pool format a tournament/game was played under. Each discipline in
+/// `RatingSettings::disciplines` gets its own rating tables — a strong 8-ball
+/// player and a strong 9-ball player aren't comparable, and the two formats
+/// have very different scoring variance. A combined table over all
+/// disciplines is also produced when `RatingSettings::combine_disciplines`
+/// is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Discipline {
+    EightBall,
+    NineBall,
+    TenBall,
+    StraightPool,
+    Other,
+}
+
+impl Discipline {
+    /// CueScore's `discipline` field is a free-form string (e.g. "9-Ball",
+    /// "8 Ball", "Straight Pool"); anything we don't recognize collapses
+    /// into `Other` rather than failing ingestion.
+    pub fn from_cuescore_str(raw: Option<&str>) -> Self {
+        let lower = match raw {
+            Some(s) => s.to_lowercase(),
+            None => return Discipline::Other,
+        };
+
+        if lower.contains("10") {
+            Discipline::TenBall
+        } else if lower.contains('9') {
+            Discipline::NineBall
+        } else if lower.contains('8') {
+            Discipline::EightBall
+        } else if lower.contains("straight") || lower.contains("14") {
+            Discipline::StraightPool
+        } else {
+            Discipline::Other
+        }
+    }
+
+    /// Short slug used as the `{discipline}_{period}` prefix in `rating_type`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Discipline::EightBall => "8ball",
+            Discipline::NineBall => "9ball",
+            Discipline::TenBall => "10ball",
+            Discipline::StraightPool => "straight",
+            Discipline::Other => "other",
+        }
+    }
+
+    /// Parses one of `as_str`'s slugs back into a `Discipline`, for API
+    /// query params that scope a request to a single discipline.
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug {
+            "8ball" => Some(Discipline::EightBall),
+            "9ball" => Some(Discipline::NineBall),
+            "10ball" => Some(Discipline::TenBall),
+            "straight" => Some(Discipline::StraightPool),
+            "other" => Some(Discipline::Other),
+            _ => None,
+        }
+    }
 }
 
 /// Player data
@@ -31,6 +95,7 @@ pub struct Game {
     pub second_player_score: i32,
     pub date: DateTime<Utc>,
     pub weight: f64, // Time decay weight
+    pub discipline: Discipline,
 }
 
 /// Player rating
@@ -110,6 +175,10 @@ impl TournamentResponse {
             .map(|v| v.name.clone())
             .unwrap_or_else(|| "Unknown".to_string())
     }
+
+    pub fn discipline(&self) -> Discipline {
+        Discipline::from_cuescore_str(self.discipline.as_deref())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -0,0 +1,240 @@
+use anyhow::{Context, Result};
+use log::info;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use crate::config::settings::ScraperSettings;
+use crate::domain::{MatchResponse, PlayerInfo, TournamentResponse};
+use crate::fetchers::TournamentSource;
+use crate::http::RateLimitedClient;
+
+const API_BASE_URL: &str = "https://api.challonge.com/v1";
+const RATE_LIMIT_MS: u64 = 1000;
+const USER_AGENT: &str = "WarsawPoolRankings/2.0";
+const TIMEOUT_SECS: u64 = 30;
+
+/// Challonge API client, implementing `TournamentSource` as an alternative
+/// to CueScore's `CueScoreClient` + `VenueScraper` pair. Challonge has no
+/// "venue" concept of its own — `list_venue_tournaments`'s `venue_name` is
+/// used as the organizer subdomain instead, and `venue_id` is ignored.
+pub struct ChallongeClient {
+    client: RateLimitedClient,
+    api_key: String,
+}
+
+impl ChallongeClient {
+    pub fn new(settings: &ScraperSettings, api_key: String) -> Result<Self> {
+        let client = RateLimitedClient::new(
+            USER_AGENT,
+            TIMEOUT_SECS,
+            RATE_LIMIT_MS,
+            settings.max_retries,
+            settings.base_backoff_ms,
+        )?;
+        Ok(Self { client, api_key })
+    }
+
+    fn build_tournament_url(&self, tournament_id: i64) -> String {
+        format!(
+            "{}/tournaments/{}.json?api_key={}&include_participants=1&include_matches=1",
+            API_BASE_URL, tournament_id, self.api_key
+        )
+    }
+
+    fn build_index_url(&self, subdomain: &str) -> String {
+        format!(
+            "{}/tournaments.json?api_key={}&subdomain={}",
+            API_BASE_URL, self.api_key, subdomain
+        )
+    }
+
+    /// `scores_csv` is a comma-separated list of per-game scores, e.g.
+    /// `"6-2,4-6,6-3"`. Counts how many of those games each side won, which
+    /// is what the crate's `score_a`/`score_b` (match-level game counts)
+    /// actually track for CueScore matches too.
+    fn count_games_won(scores_csv: &str) -> (i32, i32) {
+        let mut wins_a = 0;
+        let mut wins_b = 0;
+        for game in scores_csv.split(',') {
+            let mut parts = game.trim().splitn(2, '-');
+            let a = parts.next().and_then(|s| s.trim().parse::<i32>().ok());
+            let b = parts.next().and_then(|s| s.trim().parse::<i32>().ok());
+            if let (Some(a), Some(b)) = (a, b) {
+                if a > b {
+                    wins_a += 1;
+                } else if b > a {
+                    wins_b += 1;
+                }
+            }
+        }
+        (wins_a, wins_b)
+    }
+
+    fn participant_name(participants: &[ChallongeParticipantEnvelope], id: Option<i64>) -> String {
+        id.and_then(|id| {
+            participants
+                .iter()
+                .find(|p| p.participant.id == id)
+                .map(|p| p.participant.name.clone())
+        })
+        .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    fn map_tournament(raw: ChallongeTournament) -> TournamentResponse {
+        let participants = raw.participants;
+        let matches = raw
+            .matches
+            .into_iter()
+            .map(|m| m.m)
+            .map(|m| {
+                let (score_a, score_b) = m
+                    .scores_csv
+                    .as_deref()
+                    .map(Self::count_games_won)
+                    .unwrap_or((0, 0));
+
+                MatchResponse {
+                    match_id: m.id,
+                    player_a: PlayerInfo {
+                        player_id: m.player1_id,
+                        team_id: None,
+                        name: Self::participant_name(&participants, m.player1_id),
+                    },
+                    player_b: PlayerInfo {
+                        player_id: m.player2_id,
+                        team_id: None,
+                        name: Self::participant_name(&participants, m.player2_id),
+                    },
+                    score_a,
+                    score_b,
+                    starttime: m.started_at.unwrap_or_default(),
+                    stoptime: m.completed_at,
+                }
+            })
+            .collect();
+
+        TournamentResponse {
+            id: raw.id,
+            name: raw.name,
+            starttime: raw.started_at.unwrap_or_default(),
+            stoptime: raw.completed_at,
+            tournament_type: None,
+            format: None,
+            breakrule: None,
+            description: None,
+            discipline: None,
+            venues: None,
+            banner: serde_json::Value::Null,
+            dresscode: None,
+            default_race_to: None,
+            url: raw.url,
+            timezone: None,
+            display_date: None,
+            deadline: None,
+            matches,
+        }
+    }
+}
+
+impl TournamentSource for ChallongeClient {
+    async fn fetch_tournament(&mut self, tournament_id: i64) -> Result<TournamentResponse> {
+        let url = self.build_tournament_url(tournament_id);
+        info!("Fetching Challonge tournament {}", tournament_id);
+
+        let response = self.client.get(&url).await?;
+        if !response.status().is_success() {
+            anyhow::bail!("Challonge API returned status: {}", response.status());
+        }
+
+        let envelope: ChallongeTournamentEnvelope = response
+            .json()
+            .await
+            .context("Failed to parse Challonge tournament response")?;
+
+        Ok(Self::map_tournament(envelope.tournament))
+    }
+
+    async fn list_venue_tournaments(
+        &mut self,
+        _venue_id: i64,
+        venue_name: &str,
+    ) -> Result<HashSet<i64>> {
+        let url = self.build_index_url(venue_name);
+        info!("Listing Challonge tournaments for subdomain {}", venue_name);
+
+        let response = self.client.get(&url).await?;
+        if !response.status().is_success() {
+            anyhow::bail!("Challonge API returned status: {}", response.status());
+        }
+
+        let envelopes: Vec<ChallongeTournamentIndexEnvelope> = response
+            .json()
+            .await
+            .context("Failed to parse Challonge tournament index response")?;
+
+        Ok(envelopes.into_iter().map(|e| e.tournament.id).collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeTournamentEnvelope {
+    tournament: ChallongeTournament,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeTournamentIndexEnvelope {
+    tournament: ChallongeTournamentSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeTournamentSummary {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeTournament {
+    id: i64,
+    name: String,
+    #[serde(default)]
+    started_at: Option<String>,
+    #[serde(default)]
+    completed_at: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    participants: Vec<ChallongeParticipantEnvelope>,
+    #[serde(default)]
+    matches: Vec<ChallongeMatchEnvelope>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeParticipantEnvelope {
+    participant: ChallongeParticipant,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeParticipant {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeMatchEnvelope {
+    #[serde(rename = "match")]
+    m: ChallongeMatch,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChallongeMatch {
+    id: i64,
+    #[serde(default)]
+    player1_id: Option<i64>,
+    #[serde(default)]
+    player2_id: Option<i64>,
+    #[serde(default)]
+    scores_csv: Option<String>,
+    #[serde(default)]
+    started_at: Option<String>,
+    #[serde(default)]
+    completed_at: Option<String>,
+}
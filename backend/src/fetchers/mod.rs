@@ -0,0 +1,7 @@
+pub mod challonge_client;
+pub mod tournament_source;
+pub mod venue_scraper;
+
+pub use challonge_client::ChallongeClient;
+pub use tournament_source::TournamentSource;
+pub use venue_scraper::VenueScraper;
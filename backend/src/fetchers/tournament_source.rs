@@ -0,0 +1,58 @@
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+use crate::api::CueScoreClient;
+use crate::domain::TournamentResponse;
+use crate::fetchers::VenueScraper;
+
+/// A provider of tournament data ingestion can pull from. `CueScoreClient`
+/// and `VenueScraper` together cover CueScore (one fetches tournament
+/// details over its JSON API, the other discovers ids by scraping venue
+/// pages); `ChallongeClient` covers Challonge with a single client that
+/// does both. `IngestionService` picks which pair/client to use per
+/// `AppConfig::source`, so ratings aren't locked to CueScore-run events.
+#[allow(async_fn_in_trait)]
+pub trait TournamentSource {
+    /// Fetch full tournament details (name, matches, participants) for one
+    /// tournament id.
+    async fn fetch_tournament(&mut self, tournament_id: i64) -> Result<TournamentResponse>;
+
+    /// Discover the ids of every tournament run under `venue_name` (a
+    /// CueScore venue, or a Challonge organizer subdomain — the two
+    /// providers don't share a venue model, so `venue_id` is only
+    /// meaningful to CueScore and may be ignored).
+    async fn list_venue_tournaments(
+        &mut self,
+        venue_id: i64,
+        venue_name: &str,
+    ) -> Result<HashSet<i64>>;
+}
+
+impl TournamentSource for CueScoreClient {
+    async fn fetch_tournament(&mut self, tournament_id: i64) -> Result<TournamentResponse> {
+        let text = self.fetch_tournament_raw(tournament_id).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    async fn list_venue_tournaments(
+        &mut self,
+        _venue_id: i64,
+        _venue_name: &str,
+    ) -> Result<HashSet<i64>> {
+        bail!("CueScoreClient only fetches tournament details; use VenueScraper to discover ids")
+    }
+}
+
+impl TournamentSource for VenueScraper {
+    async fn fetch_tournament(&mut self, _tournament_id: i64) -> Result<TournamentResponse> {
+        bail!("VenueScraper only discovers tournament ids; use CueScoreClient to fetch details")
+    }
+
+    async fn list_venue_tournaments(
+        &mut self,
+        venue_id: i64,
+        venue_name: &str,
+    ) -> Result<HashSet<i64>> {
+        self.scrape_venue_tournaments(venue_id, venue_name, None, None).await
+    }
+}
@@ -1,9 +1,11 @@
 use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
 use log::{info, debug, warn};
 use regex::Regex;
 use scraper::{Html, Selector};
 use std::collections::HashSet;
 
+use crate::config::settings::ScraperSettings;
 use crate::http::RateLimitedClient;
 use crate::pagination::{PageIterator, PaginationConfig};
 
@@ -20,8 +22,14 @@ pub struct VenueScraper {
 
 impl VenueScraper {
     /// Create a new venue scraper
-    pub fn new() -> Result<Self> {
-        let client = RateLimitedClient::new(USER_AGENT, TIMEOUT_SECS, RATE_LIMIT_MS)?;
+    pub fn new(settings: &ScraperSettings) -> Result<Self> {
+        let client = RateLimitedClient::new(
+            USER_AGENT,
+            TIMEOUT_SECS,
+            RATE_LIMIT_MS,
+            settings.max_retries,
+            settings.base_backoff_ms,
+        )?;
         let tournament_id_regex = Self::compile_regex()?;
 
         Ok(Self {
@@ -30,12 +38,17 @@ impl VenueScraper {
         })
     }
 
-    /// Scrape tournament IDs from a venue's tournament pages
+    /// Scrape tournament IDs from a venue's tournament pages.
+    ///
+    /// `since`, when given, is passed to the listing as a lower bound so
+    /// only tournaments started/ended after that point come back — used for
+    /// incremental sync instead of re-walking a venue's full history.
     pub async fn scrape_venue_tournaments(
         &mut self,
         venue_id: i64,
         venue_name: &str,
         max_pages: Option<usize>,
+        since: Option<NaiveDateTime>,
     ) -> Result<HashSet<i64>> {
         info!("Discovering tournaments from venue: {} (ID: {})", venue_name, venue_id);
 
@@ -49,7 +62,7 @@ impl VenueScraper {
                 break;
             }
 
-            let url = Self::build_url(&venue_name_encoded, venue_id, pages.current_page());
+            let url = Self::build_url(&venue_name_encoded, venue_id, pages.current_page(), since);
             info!("  → Page {}...", pages.current_page());
 
             let html = match self.fetch_page(&url).await {
@@ -105,9 +118,14 @@ impl VenueScraper {
 
     // --- URL Building ---
 
-    fn build_url(venue_name: &str, venue_id: i64, page: usize) -> String {
+    fn build_url(venue_name: &str, venue_id: i64, page: usize, since: Option<NaiveDateTime>) -> String {
         let base = format!("{}/venue/{}/{}/tournaments", BASE_URL, venue_name, venue_id);
-        crate::pagination::build_paginated_url_with_params(&base, page)
+        let url = crate::pagination::build_paginated_url_with_params(&base, page);
+
+        match since {
+            Some(since) => format!("{}&since={}", url, since.and_utc().timestamp()),
+            None => url,
+        }
     }
     
     fn encode_venue_name_for_url(name: &str) -> String {
@@ -1,28 +1,141 @@
 use crate::rate_limiter::RateLimiter;
 use anyhow::{Context, Result};
-use reqwest::Client;
+use chrono::Utc;
+use log::warn;
+use reqwest::{Client, StatusCode};
 use std::time::Duration;
 
-/// HTTP client with built-in rate limiting
+/// HTTP client with built-in rate limiting and 429/503 backpressure handling.
+///
+/// A throttled response is retried in place, honoring `Retry-After` when the
+/// server sends one and falling back to exponential backoff with jitter
+/// otherwise, up to `max_retries`.
 pub struct RateLimitedClient {
     client: Client,
     rate_limiter: RateLimiter,
+    max_retries: u32,
+    base_backoff: Duration,
 }
 
 impl RateLimitedClient {
-    pub fn new(user_agent: &str, timeout_secs: u64, rate_limit_ms: u64) -> Result<Self> {
+    pub fn new(
+        user_agent: &str,
+        timeout_secs: u64,
+        rate_limit_ms: u64,
+        max_retries: u32,
+        base_backoff_ms: u64,
+    ) -> Result<Self> {
         let client = Self::build_client(user_agent, timeout_secs)?;
         let rate_limiter = RateLimiter::new(rate_limit_ms);
 
         Ok(Self {
             client,
             rate_limiter,
+            max_retries,
+            base_backoff: Duration::from_millis(base_backoff_ms),
         })
     }
 
     pub async fn get(&mut self, url: &str) -> Result<reqwest::Response> {
-        self.rate_limiter.wait().await;
-        self.send_get_request(url).await
+        for attempt in 0..=self.max_retries {
+            self.rate_limiter.wait().await;
+            let response = self.send_get_request(url).await?;
+
+            if !Self::is_throttled(&response) {
+                self.rate_limiter.record_success();
+                return Ok(response);
+            }
+
+            self.rate_limiter.record_throttle();
+
+            if attempt == self.max_retries {
+                warn!(
+                    "Still throttled after {} retries, giving up: {}",
+                    self.max_retries,
+                    Self::redact_url(url)
+                );
+                return Ok(response);
+            }
+
+            let backoff = self.retry_delay(&response, attempt);
+            warn!(
+                "Throttled ({}) fetching {} — retry {}/{} in {:?}",
+                response.status(), Self::redact_url(url), attempt + 1, self.max_retries, backoff
+            );
+            tokio::time::sleep(backoff).await;
+        }
+
+        unreachable!("loop above always returns within max_retries + 1 attempts")
+    }
+
+    /// Query-string param names treated as secrets, redacted before any URL
+    /// containing them reaches the log.
+    const SENSITIVE_QUERY_PARAMS: &[&str] = &["api_key", "apikey", "token", "access_token", "secret"];
+
+    /// Replaces the value of any `SENSITIVE_QUERY_PARAMS` entry in `url`'s
+    /// query string with `REDACTED`. Falls back to the original string if
+    /// `url` doesn't parse (shouldn't happen — we just fetched it).
+    fn redact_url(url: &str) -> String {
+        let Ok(mut parsed) = reqwest::Url::parse(url) else {
+            return url.to_string();
+        };
+
+        let redacted_pairs: Vec<(String, String)> = parsed
+            .query_pairs()
+            .map(|(k, v)| {
+                if Self::SENSITIVE_QUERY_PARAMS.contains(&k.to_lowercase().as_str()) {
+                    (k.into_owned(), "REDACTED".to_string())
+                } else {
+                    (k.into_owned(), v.into_owned())
+                }
+            })
+            .collect();
+
+        parsed.query_pairs_mut().clear().extend_pairs(&redacted_pairs);
+        parsed.to_string()
+    }
+
+    fn is_throttled(response: &reqwest::Response) -> bool {
+        matches!(
+            response.status(),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        )
+    }
+
+    fn retry_delay(&self, response: &reqwest::Response, attempt: u32) -> Duration {
+        Self::parse_retry_after(response).unwrap_or_else(|| self.exponential_backoff(attempt))
+    }
+
+    /// Parses `Retry-After` as either a number of seconds or an HTTP-date.
+    fn parse_retry_after(response: &reqwest::Response) -> Option<Duration> {
+        let value = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)?
+            .to_str()
+            .ok()?;
+
+        if let Ok(secs) = value.parse::<u64>() {
+            return Some(Duration::from_secs(secs));
+        }
+
+        let target = chrono::DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+        (target - Utc::now()).to_std().ok()
+    }
+
+    fn exponential_backoff(&self, attempt: u32) -> Duration {
+        let base_ms = self.base_backoff.as_millis() as u64;
+        let exp_ms = base_ms.saturating_mul(2u64.saturating_pow(attempt));
+        Duration::from_millis(exp_ms + Self::jitter_ms(exp_ms / 4 + 1))
+    }
+
+    /// Cheap clock-derived jitter so concurrent retries don't all land in
+    /// the same instant; no need for a full RNG dependency for this.
+    fn jitter_ms(bound: u64) -> u64 {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as u64)
+            .unwrap_or(0);
+        nanos % bound.max(1)
     }
 
     fn build_client(user_agent: &str, timeout_secs: u64) -> Result<Client> {
@@ -1,4 +1,6 @@
+pub mod advantage_graph;
 pub mod api;
+pub mod auth;
 pub mod cache;
 pub mod cli;
 pub mod config;
@@ -10,6 +12,7 @@ pub mod http;
 pub mod pagination;
 pub mod rate_limiter;
 pub mod rating;
+pub mod seeding;
 pub mod services;
 
 use anyhow::Result;
@@ -17,7 +20,7 @@ use clap::Parser;
 use cli::Cli;
 
 use crate::cli::Command;
-use crate::config::settings::AppConfig;
+use crate::config::settings::{AppConfig, TournamentSourceKind};
 use crate::services::ingestion::IngestionService;
 use crate::services::processing::ProcessingService;
 use crate::services::server::ServerService;
@@ -36,11 +39,16 @@ pub fn handle_serve(port: u16) -> Result<()> {
     })
 }
 
-pub fn handle_ingest() -> Result<()> {
+pub fn handle_ingest(full: bool, source: &str) -> Result<()> {
     let runtime = tokio::runtime::Runtime::new()?;
     runtime.block_on(async {
-        let mut service = IngestionService::new()?;
-        service.run().await
+        let mut config = AppConfig::new();
+        config.source.kind = match source {
+            "challonge" => TournamentSourceKind::Challonge,
+            _ => TournamentSourceKind::CueScore,
+        };
+        let mut service = IngestionService::new(config)?;
+        service.run(full).await
     })
 }
 
@@ -48,4 +56,11 @@ pub fn handle_process() -> Result<()> {
     let config = AppConfig::new();
     let service = ProcessingService::new(config)?;
     service.run()
+}
+
+pub fn handle_mint_admin_token(subject: &str) -> Result<()> {
+    let config = AppConfig::new();
+    let token = crate::auth::mint_admin_token(&config.admin, subject)?;
+    println!("{token}");
+    Ok(())
 }
\ No newline at end of file
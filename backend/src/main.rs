@@ -1,7 +1,7 @@
 use anyhow::Result;
 
 use warsaw_pool_ranking::cli::Command;
-use warsaw_pool_ranking::{handle_ingest, handle_process, handle_serve, interpret};
+use warsaw_pool_ranking::{handle_ingest, handle_mint_admin_token, handle_process, handle_serve, interpret};
 
 fn main() {
     setup_logging();
@@ -23,7 +23,8 @@ fn parse_and_execute() -> Result<()> {
 fn execute_command(command: &Command) -> Result<()> {
     match command {
         Command::Serve { port } => handle_serve(*port),
-        Command::Ingest => handle_ingest(),
+        Command::Ingest { full, source } => handle_ingest(*full, source),
         Command::Process => handle_process(),
+        Command::MintAdminToken { subject } => handle_mint_admin_token(subject),
     }
 }
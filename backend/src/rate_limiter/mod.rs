@@ -1,10 +1,21 @@
 use std::time::Duration;
 use tokio::time::sleep;
 
-/// Controls the rate of requests to prevent API throttling
+const MIN_DELAY_MS: u64 = 50;
+const MAX_DELAY_MS: u64 = 30_000;
+const THROTTLE_BACKOFF_FACTOR: f64 = 2.0;
+const SUSTAINED_SUCCESS_RELIEF_FACTOR: f64 = 0.9;
+/// How many throttle-free requests in a row before we ease the delay back down.
+const SUSTAINED_SUCCESS_STREAK: u32 = 20;
+
+/// Controls the rate of requests to prevent API throttling. Starts at a
+/// fixed delay but self-tunes toward the server's real limit: a 429/503
+/// multiplicatively increases the delay, and a sustained run of untouched
+/// successes slowly relaxes it again.
 pub struct RateLimiter {
     delay: Duration,
     request_count: usize,
+    success_streak: u32,
 }
 
 impl RateLimiter {
@@ -12,6 +23,7 @@ impl RateLimiter {
         Self {
             delay: Duration::from_millis(delay_ms),
             request_count: 0,
+            success_streak: 0,
         }
     }
 
@@ -26,6 +38,27 @@ impl RateLimiter {
         self.request_count = 0;
     }
 
+    /// Record a request that completed without being throttled.
+    pub fn record_success(&mut self) {
+        self.success_streak += 1;
+        if self.success_streak >= SUSTAINED_SUCCESS_STREAK {
+            self.success_streak = 0;
+            self.scale_delay(SUSTAINED_SUCCESS_RELIEF_FACTOR);
+        }
+    }
+
+    /// Record a 429/503 response: back off immediately and reset the streak
+    /// so we don't relax the delay again right away.
+    pub fn record_throttle(&mut self) {
+        self.success_streak = 0;
+        self.scale_delay(THROTTLE_BACKOFF_FACTOR);
+    }
+
+    fn scale_delay(&mut self, factor: f64) {
+        let scaled_ms = (self.delay.as_millis() as f64 * factor) as u64;
+        self.delay = Duration::from_millis(scaled_ms.clamp(MIN_DELAY_MS, MAX_DELAY_MS));
+    }
+
     fn should_wait(&self) -> bool {
         self.request_count > 0
     }
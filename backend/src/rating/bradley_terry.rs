@@ -1,13 +1,21 @@
 use std::collections::HashMap;
-use ndarray::{Array1, Array2};
+use ndarray::Array1;
+use chrono::{DateTime, Utc};
 use log::info;
 
+use super::deviation::compute_rating_deviation;
 use super::types::{GameResult, PlayerId, PlayerRating, ConfidenceLevel};
 use crate::config::settings::RatingSettings;
 
 /// Calculates ratings using the MM (Minorization-Maximization) algorithm
 /// This is O(N) per iteration rather than O(N*M) of the naive approach
-pub fn calculate_ratings(games: &[GameResult], config: &RatingSettings) -> Vec<PlayerRating> {
+pub fn calculate_ratings(
+    games: &[GameResult],
+    config: &RatingSettings,
+    last_played: &HashMap<PlayerId, DateTime<Utc>>,
+    now: DateTime<Utc>,
+    warm_start: &HashMap<PlayerId, f64>,
+) -> Vec<PlayerRating> {
     info!("Calculating ratings for {} games using MM algorithm", games.len());
 
     // 1. Map PlayerIds to dense indices (0..N)
@@ -24,14 +32,31 @@ pub fn calculate_ratings(games: &[GameResult], config: &RatingSettings) -> Vec<P
     // 2. Count games per player (for statistics)
     let games_count = count_games_per_player(games);
 
-    // 3. Build comparison matrix and wins vector
-    let (comparison_matrix, wins) = build_comparison_data(games, &player_to_idx, n_players, config);
+    // 3. Build the opponent adjacency list and wins vector
+    let (adjacency, wins) = build_comparison_data(games, &player_to_idx, n_players, config);
 
-    // 4. Run MM algorithm
-    let log_ratings = mm_algorithm(&comparison_matrix, &wins, n_players, config);
+    // 4. Run MM algorithm, seeded from `warm_start` (a prior run's
+    // log-gammas) when given, rather than always starting from gamma=1.
+    let initial_log_gamma = build_initial_log_gamma(&player_ids, warm_start);
+    let log_ratings = mm_algorithm(&adjacency, &wins, n_players, config, &initial_log_gamma);
 
     // 5. Convert results back to PlayerRating objects
-    build_player_ratings(&player_ids, &log_ratings, &games_count, config)
+    build_player_ratings(&player_ids, &log_ratings, &games_count, config, last_played, now)
+}
+
+/// Approximate inverse of the `rating_value` transform in
+/// `build_player_ratings`, used to turn a previously-saved rating back into
+/// a log-gamma to warm-start the MM solver with. Not exact for players who
+/// were still being blended toward `starter_rating` (`games_played <
+/// established_games`), since that blend isn't invertible without knowing
+/// their game count at the time — close enough to save iterations, though,
+/// since the MM update still converges from any starting point.
+pub fn log_gamma_from_rating(rating: f64, config: &RatingSettings) -> f64 {
+    (rating - config.starter_rating) * std::f64::consts::LN_2 / 100.0
+}
+
+fn build_initial_log_gamma(player_ids: &[PlayerId], warm_start: &HashMap<PlayerId, f64>) -> Array1<f64> {
+    Array1::from_iter(player_ids.iter().map(|id| warm_start.get(id).copied().unwrap_or(0.0)))
 }
 
 fn extract_player_ids(games: &[GameResult]) -> Vec<PlayerId> {
@@ -54,41 +79,55 @@ fn count_games_per_player(games: &[GameResult]) -> HashMap<PlayerId, i32> {
     counts
 }
 
+/// `adjacency[i]` lists every opponent `i` has faced as `(j, total_weight)`,
+/// replacing the old `n_players x n_players` dense matrix: most players only
+/// ever face a small slice of the field, so a full row per player wasted
+/// memory and the MM update scanned a row of mostly-zero entries.
+type Adjacency = Vec<Vec<(usize, f64)>>;
+
 fn build_comparison_data(
     games: &[GameResult],
     player_to_idx: &HashMap<PlayerId, usize>,
     n_players: usize,
     _config: &RatingSettings,
-) -> (Array2<f64>, Array1<f64>) {
-    // Note: For extremely large N, a dense matrix might be too memory intensive.
-    let mut comparison_matrix = Array2::<f64>::zeros((n_players, n_players));
+) -> (Adjacency, Array1<f64>) {
+    let mut adjacency: Adjacency = vec![Vec::new(); n_players];
     let mut wins = Array1::<f64>::zeros(n_players);
 
+    // Collapse repeat pairings into a single adjacency entry per opponent
+    // rather than pushing a new one per game.
+    let mut pair_weights: HashMap<(usize, usize), f64> = HashMap::new();
+
     for game in games {
         let i = player_to_idx[&game.winner_id]; // winner
         let j = player_to_idx[&game.loser_id];  // loser
         let weight = game.weight;
 
-        // Update comparison counts (total weight of games between i and j)
-        comparison_matrix[[i, j]] += weight;
-        comparison_matrix[[j, i]] += weight;
+        *pair_weights.entry((i, j)).or_insert(0.0) += weight;
+        *pair_weights.entry((j, i)).or_insert(0.0) += weight;
 
         // Update wins
         wins[i] += weight;
         // Loser gets 0 wins added
     }
 
-    (comparison_matrix, wins)
+    for ((i, j), weight) in pair_weights {
+        adjacency[i].push((j, weight));
+    }
+
+    (adjacency, wins)
 }
 
 fn mm_algorithm(
-    comparison_matrix: &Array2<f64>,
+    adjacency: &Adjacency,
     wins: &Array1<f64>,
     n_players: usize,
     config: &RatingSettings,
+    initial_log_gamma: &Array1<f64>,
 ) -> Array1<f64> {
-    // Initialize log-ratings to 0 (ratings = 1.0)
-    let mut log_gamma = Array1::<f64>::zeros(n_players);
+    // Start from the warm-start values (gamma=1, i.e. log_gamma=0, for any
+    // player without a prior rating).
+    let mut log_gamma = initial_log_gamma.clone();
 
     for iteration in 0..config.max_iterations {
         let mut new_log_gamma = Array1::<f64>::zeros(n_players);
@@ -98,19 +137,13 @@ fn mm_algorithm(
             let mut denominator = 0.0;
             let gamma_i = log_gamma[i].exp();
 
-            // This inner loop is the bottleneck if dense.
-            // Ideally should iterate only over neighbors.
-            for j in 0..n_players {
-                if i != j {
-                    let comparisons = comparison_matrix[[i, j]];
-                    if comparisons > 0.0 {
-                        let gamma_j = log_gamma[j].exp();
-                        
-                        denominator += comparisons / (gamma_i + gamma_j);
-                    }
-                }
+            // O(E) per player instead of O(N): only real opponents
+            // contribute a term to the denominator.
+            for &(j, comparisons) in &adjacency[i] {
+                let gamma_j = log_gamma[j].exp();
+                denominator += comparisons / (gamma_i + gamma_j);
             }
-            
+
             // Add virtual games against "average player" (gamma=1.0)
             // We simulate VIRTUAL_GAMES_WEIGHT games where we drew (0.5 win).
             // This anchors everyone to the mean (500).
@@ -150,6 +183,8 @@ fn build_player_ratings(
     log_ratings: &Array1<f64>,
     games_count: &HashMap<PlayerId, i32>,
     config: &RatingSettings,
+    last_played: &HashMap<PlayerId, DateTime<Utc>>,
+    now: DateTime<Utc>,
 ) -> Vec<PlayerRating> {
     let mut ratings = Vec::new();
 
@@ -178,12 +213,19 @@ fn build_player_ratings(
             rating_value = rating_value.clamp(0.0, 2000.0);
         }
 
+        let rating_deviation = last_played
+            .get(&player_id)
+            .map(|&last| compute_rating_deviation(last, now, config))
+            .unwrap_or(config.rd_max);
+
         ratings.push(PlayerRating {
             player_id,
             rating_type: "temp".to_string(), // Placeholder, to be overwritten by ProcessingService
             rating: rating_value,
             games_played,
-            confidence_level: ConfidenceLevel::from_games_played(games_played),
+            confidence_level: ConfidenceLevel::from_rating_deviation(rating_deviation),
+            rating_deviation,
+            volatility: None,
         });
     }
 
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+
+use crate::config::settings::RatingSettings;
+
+/// Rating deviation (RD): grows with time since a player's last game, so a
+/// 300-game veteran who hasn't played in two years doesn't read as certain
+/// as someone active last week.
+///
+/// `RD = min(sqrt(rd_base² + decay_const² · Δt_periods), rd_max)`, where
+/// `Δt_periods` is the elapsed time since `last_played` measured in
+/// `rd_period_days`-long periods.
+pub fn compute_rating_deviation(last_played: DateTime<Utc>, now: DateTime<Utc>, config: &RatingSettings) -> f64 {
+    let days_since = (now - last_played).num_days().max(0) as f64;
+    let delta_t_periods = days_since / config.rd_period_days;
+
+    (config.rd_base.powi(2) + config.decay_const.powi(2) * delta_t_periods)
+        .sqrt()
+        .min(config.rd_max)
+}
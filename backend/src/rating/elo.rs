@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::deviation::compute_rating_deviation;
+use super::types::{ConfidenceLevel, PlayerId, PlayerRating};
+use crate::config::settings::RatingSettings;
+use crate::domain::ExpandedGame;
+
+/// Online Elo, as an alternative to the batch Bradley-Terry MLE. Processes
+/// `games` in chronological order and folds each result into the current
+/// ratings, so new games can be applied without recomputing history.
+///
+/// `base_k` is the step size used while a player is still provisional; it
+/// tapers down as a player accumulates games, so new players converge to
+/// their true strength fast while veterans' ratings stay stable.
+pub fn calculate_ratings(
+    games: &[ExpandedGame],
+    config: &RatingSettings,
+    base_k: f64,
+    last_played: &HashMap<PlayerId, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Vec<PlayerRating> {
+    let mut ordered: Vec<&ExpandedGame> = games.iter().collect();
+    ordered.sort_by_key(|g| g.date);
+
+    let mut ratings: HashMap<PlayerId, f64> = HashMap::new();
+    let mut games_played: HashMap<PlayerId, i32> = HashMap::new();
+
+    for game in ordered {
+        apply_game(&mut ratings, &mut games_played, game, config, base_k);
+    }
+
+    build_player_ratings(&ratings, &games_played, config, last_played, now)
+}
+
+fn apply_game(
+    ratings: &mut HashMap<PlayerId, f64>,
+    games_played: &mut HashMap<PlayerId, i32>,
+    game: &ExpandedGame,
+    config: &RatingSettings,
+    base_k: f64,
+) {
+    let winner_id = game.winner_id as PlayerId;
+    let loser_id = game.loser_id as PlayerId;
+
+    let rating_winner = *ratings.entry(winner_id).or_insert(config.starter_rating);
+    let rating_loser = *ratings.entry(loser_id).or_insert(config.starter_rating);
+
+    let expected_winner = expected_score(rating_winner, rating_loser);
+    let expected_loser = 1.0 - expected_winner;
+
+    let k_winner = k_factor(*games_played.get(&winner_id).unwrap_or(&0), base_k);
+    let k_loser = k_factor(*games_played.get(&loser_id).unwrap_or(&0), base_k);
+
+    ratings.insert(
+        winner_id,
+        rating_winner + k_winner * game.weight * (1.0 - expected_winner),
+    );
+    ratings.insert(
+        loser_id,
+        rating_loser + k_loser * game.weight * (0.0 - expected_loser),
+    );
+
+    *games_played.entry(winner_id).or_insert(0) += 1;
+    *games_played.entry(loser_id).or_insert(0) += 1;
+}
+
+/// `expected_A = q_A / (q_A + q_B)`, where `q = 10^(rating / 400)`.
+fn expected_score(rating_a: f64, rating_b: f64) -> f64 {
+    let q_a = 10f64.powf(rating_a / 400.0);
+    let q_b = 10f64.powf(rating_b / 400.0);
+    q_a / (q_a + q_b)
+}
+
+/// Larger step size while a player's rating is still provisional, tapering
+/// down to a fraction of `base_k` once a player is established, so early
+/// games move a new player toward their true strength faster while veterans'
+/// ratings stay stable.
+fn k_factor(games_played: i32, base_k: f64) -> f64 {
+    match ConfidenceLevel::from_games_played(games_played) {
+        ConfidenceLevel::Unranked | ConfidenceLevel::Provisional => base_k,
+        ConfidenceLevel::Emerging => base_k * 0.625,
+        ConfidenceLevel::Established => base_k * 0.25,
+    }
+}
+
+fn build_player_ratings(
+    ratings: &HashMap<PlayerId, f64>,
+    games_played: &HashMap<PlayerId, i32>,
+    config: &RatingSettings,
+    last_played: &HashMap<PlayerId, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Vec<PlayerRating> {
+    ratings
+        .iter()
+        .map(|(&player_id, &rating)| {
+            let games = *games_played.get(&player_id).unwrap_or(&0);
+            let rating_deviation = last_played
+                .get(&player_id)
+                .map(|&last| compute_rating_deviation(last, now, config))
+                .unwrap_or(config.rd_max);
+
+            PlayerRating {
+                player_id,
+                rating_type: "elo".to_string(),
+                rating,
+                games_played: games,
+                confidence_level: ConfidenceLevel::from_rating_deviation(rating_deviation),
+                rating_deviation,
+                volatility: None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::types::{ConfidenceLevel, PlayerId, PlayerRating};
+use crate::domain::ExpandedGame;
+
+/// Glicko-2's internal scale factor, converting a rating/RD on the familiar
+/// 1500-centered display scale to the `μ`/`φ` scale the update math is
+/// defined on.
+const GLICKO_SCALE: f64 = 173.7178;
+const DEFAULT_RATING: f64 = 1500.0;
+const DEFAULT_RD: f64 = 350.0;
+const DEFAULT_VOLATILITY: f64 = 0.06;
+/// System constant constraining how much volatility can change per period.
+/// 0.3-1.2 is the commonly recommended range; 0.5 is a reasonable default
+/// absent domain-specific tuning.
+const TAU: f64 = 0.5;
+const VOLATILITY_CONVERGENCE_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy)]
+struct PlayerState {
+    mu: f64,
+    phi: f64,
+    sigma: f64,
+}
+
+impl Default for PlayerState {
+    fn default() -> Self {
+        Self {
+            mu: 0.0,
+            phi: DEFAULT_RD / GLICKO_SCALE,
+            sigma: DEFAULT_VOLATILITY,
+        }
+    }
+}
+
+/// Glicko-2, processing `games` one rating period at a time (games grouped
+/// by calendar date), updating every player's rating, deviation, and
+/// volatility per Glickman's algorithm. Players who sit out a period still
+/// have their deviation inflated via `φ*`, same as a real Glicko-2 system
+/// carrying an idle player forward.
+pub fn calculate_ratings(games: &[ExpandedGame]) -> Vec<PlayerRating> {
+    let periods = group_by_period(games);
+
+    let mut states: HashMap<PlayerId, PlayerState> = HashMap::new();
+    let mut games_played: HashMap<PlayerId, i32> = HashMap::new();
+
+    for period_games in &periods {
+        // Each player's results this period, as (opponent's state, score).
+        let mut results: HashMap<PlayerId, Vec<(PlayerState, f64)>> = HashMap::new();
+        for game in period_games {
+            let winner = game.winner_id as PlayerId;
+            let loser = game.loser_id as PlayerId;
+            let winner_state = *states.entry(winner).or_insert_with(PlayerState::default);
+            let loser_state = *states.entry(loser).or_insert_with(PlayerState::default);
+
+            results.entry(winner).or_default().push((loser_state, 1.0));
+            results.entry(loser).or_default().push((winner_state, 0.0));
+
+            *games_played.entry(winner).or_insert(0) += 1;
+            *games_played.entry(loser).or_insert(0) += 1;
+        }
+
+        // Every player's update uses everyone else's state as it stood at
+        // the start of the period, so results are applied all at once
+        // rather than carried over game by game within the period. Every
+        // player seen so far is updated; one with no games this period gets
+        // an empty result list, which only inflates their deviation.
+        let mut next_states = states.clone();
+        for (&player_id, &state) in &states {
+            let opponent_results = results.get(&player_id).map(Vec::as_slice).unwrap_or(&[]);
+            next_states.insert(player_id, update_player(state, opponent_results));
+        }
+        states = next_states;
+    }
+
+    build_player_ratings(&states, &games_played)
+}
+
+fn group_by_period(games: &[ExpandedGame]) -> Vec<Vec<ExpandedGame>> {
+    let mut by_date: BTreeMap<chrono::NaiveDate, Vec<ExpandedGame>> = BTreeMap::new();
+    for game in games {
+        by_date.entry(game.date.date()).or_default().push(game.clone());
+    }
+    by_date.into_values().collect()
+}
+
+/// `g(φ) = 1 / √(1 + 3φ²/π²)` — reduces the impact of a game against an
+/// opponent with a large rating deviation.
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (std::f64::consts::PI * std::f64::consts::PI)).sqrt()
+}
+
+/// `E = 1 / (1 + exp(-g(φ_j)(μ - μ_j)))` — expected score against an
+/// opponent at `(mu_j, phi_j)`.
+fn expected_score(mu: f64, mu_j: f64, phi_j: f64) -> f64 {
+    1.0 / (1.0 + (-g(phi_j) * (mu - mu_j)).exp())
+}
+
+fn update_player(state: PlayerState, opponent_results: &[(PlayerState, f64)]) -> PlayerState {
+    if opponent_results.is_empty() {
+        // Sitting out a period: rating and volatility are unchanged, but RD
+        // still inflates via φ* to reflect the growing uncertainty.
+        let phi_star = (state.phi * state.phi + state.sigma * state.sigma).sqrt();
+        return PlayerState { phi: phi_star, ..state };
+    }
+
+    let variance_inv: f64 = opponent_results
+        .iter()
+        .map(|&(opponent, _)| {
+            let g_j = g(opponent.phi);
+            let e_j = expected_score(state.mu, opponent.mu, opponent.phi);
+            g_j * g_j * e_j * (1.0 - e_j)
+        })
+        .sum();
+    let v = 1.0 / variance_inv;
+
+    let score_sum: f64 = opponent_results
+        .iter()
+        .map(|&(opponent, score)| g(opponent.phi) * (score - expected_score(state.mu, opponent.mu, opponent.phi)))
+        .sum();
+    let delta = v * score_sum;
+
+    let sigma_prime = update_volatility(state.phi, state.sigma, v, delta);
+
+    let phi_star = (state.phi * state.phi + sigma_prime * sigma_prime).sqrt();
+    let phi_prime = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+    let mu_prime = state.mu + phi_prime * phi_prime * score_sum;
+
+    PlayerState {
+        mu: mu_prime,
+        phi: phi_prime,
+        sigma: sigma_prime,
+    }
+}
+
+/// Solves `f(x) = 0` for `x = ln(σ²)` via the Illinois algorithm (a
+/// regula-falsi variant that converges faster than bisection), per
+/// Glickman's "Example of the Glicko-2 system", step 5.
+fn update_volatility(phi: f64, sigma: f64, v: f64, delta: f64) -> f64 {
+    let a = (sigma * sigma).ln();
+    let tau_sq = TAU * TAU;
+
+    let f = |x: f64| -> f64 {
+        let ex = x.exp();
+        let numerator = ex * (delta * delta - phi * phi - v - ex);
+        let denominator = 2.0 * (phi * phi + v + ex).powi(2);
+        numerator / denominator - (x - a) / tau_sq
+    };
+
+    let mut big_a = a;
+    let mut big_b = if delta * delta > phi * phi + v {
+        (delta * delta - phi * phi - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+
+    let mut f_a = f(big_a);
+    let mut f_b = f(big_b);
+
+    while (big_b - big_a).abs() > VOLATILITY_CONVERGENCE_TOLERANCE {
+        let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+        let f_c = f(big_c);
+
+        if f_c * f_b <= 0.0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2.0;
+        }
+        big_b = big_c;
+        f_b = f_c;
+    }
+
+    (big_a / 2.0).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn g_is_one_at_zero_deviation_and_shrinks_as_deviation_grows() {
+        assert_eq!(g(0.0), 1.0);
+        assert!(g(1.0) < 1.0);
+        assert!(g(2.0) < g(1.0));
+    }
+
+    #[test]
+    fn expected_score_is_even_for_equally_rated_opponents() {
+        assert_eq!(expected_score(0.0, 0.0, DEFAULT_RD / GLICKO_SCALE), 0.5);
+    }
+
+    #[test]
+    fn expected_score_favors_the_higher_rated_player() {
+        let favorite = expected_score(1.0, 0.0, DEFAULT_RD / GLICKO_SCALE);
+        assert!(favorite > 0.5);
+    }
+
+    #[test]
+    fn update_player_with_no_games_only_inflates_deviation() {
+        let state = PlayerState::default();
+        let updated = update_player(state, &[]);
+
+        assert_eq!(updated.mu, state.mu);
+        assert_eq!(updated.sigma, state.sigma);
+        assert!(updated.phi > state.phi);
+    }
+}
+
+fn build_player_ratings(
+    states: &HashMap<PlayerId, PlayerState>,
+    games_played: &HashMap<PlayerId, i32>,
+) -> Vec<PlayerRating> {
+    states
+        .iter()
+        .map(|(&player_id, state)| {
+            let rating = state.mu * GLICKO_SCALE + DEFAULT_RATING;
+            let rating_deviation = state.phi * GLICKO_SCALE;
+
+            PlayerRating {
+                player_id,
+                rating_type: "glicko2".to_string(),
+                rating,
+                games_played: *games_played.get(&player_id).unwrap_or(&0),
+                confidence_level: ConfidenceLevel::from_rating_deviation(rating_deviation),
+                rating_deviation,
+                volatility: Some(state.sigma),
+            }
+        })
+        .collect()
+}
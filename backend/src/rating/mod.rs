@@ -1,7 +1,73 @@
 pub mod bradley_terry;
+pub mod deviation;
+pub mod elo;
+pub mod glicko2;
+pub mod prediction;
 pub mod types;
 pub mod weighting;
 
-pub use bradley_terry::calculate_ratings;
-pub use types::{ConfidenceLevel, GameResult, PlayerRating};
+pub use prediction::{predict_win_probability, predict_win_probability_with_scale};
+pub use types::{ConfidenceLevel, GameResult, PlayerId, PlayerRating};
 pub use weighting::calculate_weight;
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::config::settings::{RatingAlgorithm, RatingSettings};
+use crate::domain::ExpandedGame;
+
+/// Calculates ratings for `games` using whichever algorithm `config.algorithm`
+/// selects: the batch Bradley-Terry MLE solver, the online Elo engine, or
+/// Glicko-2.
+///
+/// `warm_start` seeds the Bradley-Terry MM solver with a prior run's
+/// log-gammas (see `bradley_terry::log_gamma_from_rating`), letting the
+/// incremental processing path converge in a handful of iterations instead
+/// of refitting from scratch. Pass an empty map for a cold start; Elo and
+/// Glicko-2 ignore it, since they already update one game or period at a
+/// time.
+pub fn calculate_ratings(
+    games: &[ExpandedGame],
+    config: &RatingSettings,
+    warm_start: &HashMap<PlayerId, f64>,
+) -> Vec<PlayerRating> {
+    let last_played = last_played_by_player(games);
+    let now = Utc::now();
+
+    match &config.algorithm {
+        RatingAlgorithm::BatchMle => {
+            let game_results: Vec<GameResult> = games
+                .iter()
+                .map(|g| GameResult {
+                    winner_id: g.winner_id as PlayerId,
+                    loser_id: g.loser_id as PlayerId,
+                    weight: g.weight,
+                })
+                .collect();
+            bradley_terry::calculate_ratings(&game_results, config, &last_played, now, warm_start)
+        }
+        RatingAlgorithm::OnlineElo { base_k } => {
+            elo::calculate_ratings(games, config, *base_k, &last_played, now)
+        }
+        RatingAlgorithm::Glicko2 => glicko2::calculate_ratings(games),
+    }
+}
+
+/// Most recent game date per player, fed into `deviation::compute_rating_deviation`
+/// by the Bradley-Terry and Elo engines. Glicko-2 tracks its own deviation
+/// and does not use it.
+fn last_played_by_player(games: &[ExpandedGame]) -> HashMap<PlayerId, DateTime<Utc>> {
+    let mut last_played: HashMap<PlayerId, DateTime<Utc>> = HashMap::new();
+
+    for game in games {
+        for player_id in [game.winner_id as PlayerId, game.loser_id as PlayerId] {
+            last_played
+                .entry(player_id)
+                .and_modify(|d| *d = (*d).max(game.date))
+                .or_insert(game.date);
+        }
+    }
+
+    last_played
+}
@@ -0,0 +1,16 @@
+/// Win probability for the higher-rated player under this crate's
+/// Fargo-like scale, where 100 rating points is 2:1 odds:
+/// `P = 1 / (1 + 2^(-(rating_a - rating_b) / 100))`.
+pub fn predict_win_probability(rating_a: f64, rating_b: f64) -> f64 {
+    let rating_diff = rating_a - rating_b;
+    1.0 / (1.0 + (-rating_diff * std::f64::consts::LN_2 / 100.0).exp())
+}
+
+/// Same logistic model as `predict_win_probability`, but with an explicit
+/// scale `s` instead of the fixed Fargo-like one: `P = 1 / (1 +
+/// exp(-(rating_a - rating_b) / s))`. Lets callers like `/api/predict` expose
+/// `s` as a setting (`RatingSettings::prediction_scale`) instead of baking it
+/// in.
+pub fn predict_win_probability_with_scale(rating_a: f64, rating_b: f64, scale: f64) -> f64 {
+    1.0 / (1.0 + (-(rating_a - rating_b) / scale).exp())
+}
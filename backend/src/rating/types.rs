@@ -12,6 +12,14 @@ pub struct PlayerRating {
     pub rating: RatingValue,
     pub games_played: i32,
     pub confidence_level: ConfidenceLevel,
+    /// Rating deviation: how much uncertainty inactivity has added since the
+    /// player's last game. See `rating::deviation::compute_rating_deviation`.
+    /// Under `rating::glicko2` it is that algorithm's own deviation instead.
+    pub rating_deviation: f64,
+    /// Glicko-2 volatility (σ): how erratically a player's results swing
+    /// from period to period. `None` for algorithms other than
+    /// `rating::glicko2`, which is the only one that models it.
+    pub volatility: Option<f64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -44,6 +52,21 @@ impl ConfidenceLevel {
             ConfidenceLevel::Established => "established",
         }
     }
+
+    /// Confidence from rating deviation rather than raw game count, so a
+    /// dormant veteran's confidence decays the longer they've been inactive
+    /// instead of staying pinned at whatever it was on their last game.
+    pub fn from_rating_deviation(rd: f64) -> Self {
+        if rd <= 75.0 {
+            ConfidenceLevel::Established
+        } else if rd <= 150.0 {
+            ConfidenceLevel::Emerging
+        } else if rd <= 250.0 {
+            ConfidenceLevel::Provisional
+        } else {
+            ConfidenceLevel::Unranked
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
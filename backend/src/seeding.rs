@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::rating::predict_win_probability;
+
+/// One bracket slot: a player seed, or a bye when the field isn't a power
+/// of two. Byes go to the top seeds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeedSlot {
+    Player(i64),
+    Bye,
+}
+
+#[derive(Debug, Clone)]
+pub struct SeedingResult {
+    pub seeds: Vec<SeedSlot>,
+    /// Sum, over every first-round pairing, of the probability the higher
+    /// seed wins — a standard bracket-fairness score. Higher is "fairer"
+    /// (fewer expected early upsets).
+    pub expected_upset_score: f64,
+    /// Expected number of "chalk" (higher-rated player advances) results
+    /// across the whole bracket, not just round one: each round, the
+    /// favorite from the previous round is assumed to advance, and that
+    /// round's win probability is added to the total.
+    pub expected_correct_matches: f64,
+}
+
+/// Builds a single-elimination bracket for `player_ids`, snake-seeded by
+/// descending rating so the top seed meets the lowest seed first, the
+/// second seed meets the second-lowest, and so on.
+pub fn generate_seeding(player_ids: &[i64], ratings: &HashMap<i64, f64>) -> SeedingResult {
+    let ranked = rank_by_rating_desc(player_ids, ratings);
+    let bracket_size = next_power_of_two(ranked.len());
+    let seed_positions = fold_seed_positions(bracket_size);
+    let seeds: Vec<SeedSlot> = seed_positions
+        .into_iter()
+        .map(|seed| match ranked.get(seed - 1) {
+            Some(&player_id) => SeedSlot::Player(player_id),
+            None => SeedSlot::Bye,
+        })
+        .collect();
+    let expected_upset_score = score_first_round(&seeds, ratings);
+    let expected_correct_matches = score_all_rounds(&seeds, ratings);
+
+    SeedingResult {
+        seeds,
+        expected_upset_score,
+        expected_correct_matches,
+    }
+}
+
+fn rank_by_rating_desc(player_ids: &[i64], ratings: &HashMap<i64, f64>) -> Vec<i64> {
+    let mut ranked = player_ids.to_vec();
+    ranked.sort_by(|a, b| {
+        let rating_a = ratings.get(a).copied().unwrap_or(0.0);
+        let rating_b = ratings.get(b).copied().unwrap_or(0.0);
+        rating_b.partial_cmp(&rating_a).unwrap()
+    });
+    ranked
+}
+
+fn next_power_of_two(n: usize) -> usize {
+    if n <= 1 {
+        return 1;
+    }
+    let mut size = 1;
+    while size < n {
+        size *= 2;
+    }
+    size
+}
+
+/// Standard "fold" seed ordering for a bracket of size `bracket_size`
+/// (a power of two): `S_k = interleave(S_{k-1}, (2^k + 1) - S_{k-1})`,
+/// starting from `S_0 = [1]`, e.g. `[1, 8, 4, 5, 2, 7, 3, 6]` for 8. This is
+/// what puts seed 1 opposite the lowest seed in round one, seed 2 opposite
+/// the second-lowest, and so on.
+fn fold_seed_positions(bracket_size: usize) -> Vec<usize> {
+    let mut positions = vec![1];
+
+    while positions.len() < bracket_size {
+        let next_size = positions.len() * 2;
+        let mirrored: Vec<usize> = positions
+            .iter()
+            .map(|seed| next_size + 1 - seed)
+            .collect();
+
+        positions = interleave(&positions, &mirrored);
+    }
+
+    positions
+}
+
+fn interleave(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().zip(b.iter()).flat_map(|(&x, &y)| [x, y]).collect()
+}
+
+fn score_first_round(seeds: &[SeedSlot], ratings: &HashMap<i64, f64>) -> f64 {
+    seeds
+        .chunks(2)
+        .map(|pair| match pair {
+            [SeedSlot::Player(higher), SeedSlot::Player(lower)] => {
+                let rating_higher = ratings.get(higher).copied().unwrap_or(0.0);
+                let rating_lower = ratings.get(lower).copied().unwrap_or(0.0);
+                predict_win_probability(rating_higher, rating_lower)
+            }
+            // A bye (including a lone trailing seed with no opponent, which
+            // a 0- or 1-player bracket collapses to) is a guaranteed advance
+            // for the seed that drew it.
+            _ => 1.0,
+        })
+        .sum()
+}
+
+/// Projects the bracket round by round, assuming the favorite of each
+/// matchup is the one who advances (the "chalk" outcome), and sums that
+/// round's win probability into the total. This is the full-bracket
+/// extension of `score_first_round`: round one scores player-vs-player
+/// pairings the same way (a bye adds nothing here, since it is not a
+/// match), then each subsequent round re-scores the projected matchups among the
+/// assumed winners, so near-tied players who were spread into separate
+/// halves/quarters by `fold_seed_positions` don't get credited as likely
+/// upsets until they actually would meet.
+fn score_all_rounds(seeds: &[SeedSlot], ratings: &HashMap<i64, f64>) -> f64 {
+    let mut round = seeds.to_vec();
+    let mut total = 0.0;
+
+    while round.len() > 1 {
+        let mut next_round = Vec::with_capacity(round.len() / 2);
+
+        for pair in round.chunks(2) {
+            let winner = match pair {
+                [SeedSlot::Player(a), SeedSlot::Player(b)] => {
+                    let rating_a = ratings.get(a).copied().unwrap_or(0.0);
+                    let rating_b = ratings.get(b).copied().unwrap_or(0.0);
+                    let (favorite, rating_favorite, rating_underdog) = if rating_a >= rating_b {
+                        (*a, rating_a, rating_b)
+                    } else {
+                        (*b, rating_b, rating_a)
+                    };
+                    total += predict_win_probability(rating_favorite, rating_underdog);
+                    SeedSlot::Player(favorite)
+                }
+                [SeedSlot::Player(player), SeedSlot::Bye]
+                | [SeedSlot::Bye, SeedSlot::Player(player)] => SeedSlot::Player(*player),
+                [SeedSlot::Bye, SeedSlot::Bye] => SeedSlot::Bye,
+                // A lone trailing seed with no opponent (a 0- or 1-player
+                // bracket collapses to this) advances untested.
+                [single] => single.clone(),
+                _ => unreachable!("chunks(2) never yields more than 2 elements"),
+            };
+            next_round.push(winner);
+        }
+
+        round = next_round;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_seeding_does_not_panic_on_zero_players() {
+        let result = generate_seeding(&[], &HashMap::new());
+        assert_eq!(result.seeds, vec![SeedSlot::Bye]);
+    }
+
+    #[test]
+    fn generate_seeding_does_not_panic_on_one_player() {
+        let ratings = HashMap::from([(1, 1500.0)]);
+        let result = generate_seeding(&[1], &ratings);
+        assert_eq!(result.seeds, vec![SeedSlot::Player(1)]);
+        assert_eq!(result.expected_upset_score, 1.0);
+        assert_eq!(result.expected_correct_matches, 0.0);
+    }
+}
@@ -1,87 +1,294 @@
-use std::collections::HashSet;
-use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use anyhow::{Context, Result};
+use chrono::{NaiveDateTime, Utc};
 use log::info;
 
 use crate::api::CueScoreClient;
 use crate::cache::Cache;
 use crate::config::get_venues;
+use crate::config::settings::{AppConfig, TournamentSourceKind};
+use crate::database::{self, DbPool};
 use crate::domain::{FetchProgress, TournamentCollection};
-use crate::fetchers::VenueScraper;
+use crate::fetchers::{ChallongeClient, TournamentSource, VenueScraper};
+
+/// Which provider this run's tournaments are being pulled from, per
+/// `AppConfig::source`. CueScore keeps its own two-client split (scraper
+/// for id discovery, API client for details, each with CueScore-specific
+/// extras like `since`-filtered scraping); Challonge is a single client
+/// that implements `TournamentSource` for both.
+enum ActiveSource {
+    CueScore {
+        scraper: VenueScraper,
+        client: CueScoreClient,
+    },
+    Challonge(ChallongeClient),
+}
 
 pub struct IngestionService {
     cache: Cache,
-    scraper: VenueScraper,
-    api_client: CueScoreClient,
+    source: ActiveSource,
+    pool: DbPool,
 }
 
 impl IngestionService {
-    pub fn new() -> Result<Self> {
+    pub fn new(config: AppConfig) -> Result<Self> {
+        let db_path = std::env::var("DATABASE_PATH")
+            .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
+
+        let source = match config.source.kind {
+            TournamentSourceKind::CueScore => ActiveSource::CueScore {
+                scraper: VenueScraper::new(&config.scraper)?,
+                client: CueScoreClient::new(&config.scraper)?,
+            },
+            TournamentSourceKind::Challonge => {
+                let api_key = config
+                    .source
+                    .challonge_api_key
+                    .clone()
+                    .context("source is Challonge but CHALLONGE_API_KEY is not set")?;
+                ActiveSource::Challonge(ChallongeClient::new(&config.scraper, api_key)?)
+            }
+        };
+
         Ok(Self {
             cache: Cache::new("cache")?,
-            scraper: VenueScraper::new()?,
-            api_client: CueScoreClient::new()?,
+            source,
+            pool: database::create_pool(&db_path)?,
         })
     }
 
-    pub async fn run(&mut self) -> Result<()> {
+    /// Run ingestion. When `full` is true, stored per-venue sync state is
+    /// ignored and every venue is re-pulled from scratch; otherwise only
+    /// tournaments newer than each venue's last sync are fetched.
+    pub async fn run(&mut self, full: bool) -> Result<()> {
         info!("=== Starting Data Ingestion ===\n");
 
         // Step 1: Discover tournaments
-        let tournament_ids = self.discover_tournaments().await?;
+        let (tournament_ids, watermarks) = self.discover_tournaments(full).await?;
         info!("  → Found {} unique tournaments\n", tournament_ids.len());
 
         // Step 2: Fetch tournament data
-        let collection = self.fetch_tournaments(tournament_ids).await?;
+        let (collection, venue_high_water_marks) =
+            self.fetch_tournaments(tournament_ids.clone(), &watermarks).await?;
         info!("  → Fetched {} tournaments with data\n", collection.len());
 
         // Step 3: Save to parsed cache
         self.save_parsed_cache(collection)?;
         info!("  → Saved to parsed cache\n");
 
+        // Step 4: Only now that the run succeeded, advance each venue's and
+        // each tournament's high-water mark. Venues advance to the newest
+        // tournament start date actually seen this run rather than "now",
+        // so a tournament whose results get entered into CueScore
+        // retroactively isn't skipped by a watermark that already raced
+        // past it.
+        self.commit_sync_state(&venue_high_water_marks, &tournament_ids)?;
+        info!("  → Updated sync state for all venues\n");
+
         info!("=== Ingestion Complete ===");
         Ok(())
     }
 
-    async fn discover_tournaments(&mut self) -> Result<HashSet<i64>> {
+    /// Returns every discovered tournament id, plus the per-venue `since`
+    /// watermark actually used for each venue (when one was). The watermark
+    /// map comes back alongside the ids so `fetch_tournaments` can defend
+    /// against a tournament slipping through the scraper's own `since`
+    /// filter (e.g. CueScore ignoring the query param) by re-checking its
+    /// date against the same cutoff.
+    async fn discover_tournaments(&mut self, full: bool) -> Result<(HashSet<i64>, HashMap<i64, NaiveDateTime>)> {
         info!("Step 1: Discovering tournaments from venues...");
 
         let venues = get_venues();
         let mut all_ids = HashSet::new();
+        let mut watermarks = HashMap::new();
 
         for venue in venues {
-            let ids = self.scraper.scrape_venue_tournaments(venue.id, venue.name, None).await?;
+            let since = self.lower_bound_for_venue(venue.id, full)?;
+            if let Some(since) = since {
+                watermarks.insert(venue.id, since);
+            }
+
+            let ids = self.discover_ids(venue.id, venue.name, since).await?;
             all_ids.extend(ids);
         }
 
-        Ok(all_ids)
+        Ok((all_ids, watermarks))
+    }
+
+    /// Discover tournament ids for one venue, via whichever provider is
+    /// active. Only CueScore's scraper gets the `since` lower bound pushed
+    /// down into the request itself — Challonge's listing has no matching
+    /// query param, so its ids are filtered against the watermark later in
+    /// `fetch_tournaments` like everything else.
+    async fn discover_ids(
+        &mut self,
+        venue_id: i64,
+        venue_name: &str,
+        since: Option<NaiveDateTime>,
+    ) -> Result<HashSet<i64>> {
+        match &mut self.source {
+            ActiveSource::CueScore { scraper, .. } => {
+                scraper.scrape_venue_tournaments(venue_id, venue_name, None, since).await
+            }
+            ActiveSource::Challonge(client) => client.list_venue_tournaments(venue_id, venue_name).await,
+        }
+    }
+
+    fn lower_bound_for_venue(&self, venue_id: i64, full: bool) -> Result<Option<NaiveDateTime>> {
+        if full {
+            return Ok(None);
+        }
+        database::sync_state::get_last_sync(&self.pool, venue_id)
     }
 
-    async fn fetch_tournaments(&mut self, tournament_ids: HashSet<i64>) -> Result<TournamentCollection> {
+    /// `venue_high_water_marks` only covers venues that actually had a
+    /// tournament survive this run's watermark filter — a venue with
+    /// nothing new is left untouched rather than bumped to "now", so a
+    /// failed or empty fetch never advances past tournaments it didn't see.
+    fn commit_sync_state(
+        &self,
+        venue_high_water_marks: &HashMap<i64, NaiveDateTime>,
+        tournament_ids: &HashSet<i64>,
+    ) -> Result<()> {
+        for (&venue_id, &high_water_mark) in venue_high_water_marks {
+            database::sync_state::update_last_sync(&self.pool, venue_id, high_water_mark)?;
+        }
+
+        let synced_at = Utc::now().naive_utc();
+        for &tournament_id in tournament_ids {
+            database::tournament_sync::update_last_sync(&self.pool, tournament_id, synced_at)?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_tournaments(
+        &mut self,
+        tournament_ids: HashSet<i64>,
+        watermarks: &HashMap<i64, NaiveDateTime>,
+    ) -> Result<(TournamentCollection, HashMap<i64, NaiveDateTime>)> {
         info!("Step 2: Fetching tournament details...");
 
         let total = tournament_ids.len();
         let mut progress = FetchProgress::new(total);
         let mut collection = TournamentCollection::new();
+        let mut venue_high_water_marks: HashMap<i64, NaiveDateTime> = HashMap::new();
 
         for tournament_id in tournament_ids {
             let was_cached = self.is_cached(tournament_id)?;
 
+            if was_cached && self.needs_refresh(tournament_id)? {
+                self.cache.invalidate_raw(&tournament_id.to_string())?;
+            }
+
             if let Some(tournament) = self.fetch_single_tournament(tournament_id).await? {
-                collection.add(tournament);
+                if self.predates_watermark(&tournament, watermarks) {
+                    info!("  → Skipping tournament {} (older than venue's last sync)", tournament_id);
+                } else {
+                    Self::record_high_water_mark(&mut venue_high_water_marks, &tournament);
+                    collection.add(tournament);
+                }
             }
 
             self.update_progress(&mut progress, was_cached);
         }
 
-        Ok(collection)
+        Ok((collection, venue_high_water_marks))
+    }
+
+    /// Tracks, per venue, the latest `starttime` among the tournaments kept
+    /// this run, so `commit_sync_state` can advance that venue's watermark
+    /// to the content date instead of the wall-clock time the sync ran.
+    fn record_high_water_mark(
+        marks: &mut HashMap<i64, NaiveDateTime>,
+        tournament: &crate::domain::TournamentResponse,
+    ) {
+        let Some(start) = Self::parse_cuescore_date(&tournament.starttime) else {
+            return;
+        };
+
+        marks
+            .entry(tournament.venue_id())
+            .and_modify(|existing| *existing = (*existing).max(start))
+            .or_insert(start);
+    }
+
+    /// True when `tournament`'s own start date predates its venue's `since`
+    /// watermark. A belt-and-braces check on top of the scraper's `since`
+    /// query param, in case CueScore returns a listing page that doesn't
+    /// honor it.
+    fn predates_watermark(
+        &self,
+        tournament: &crate::domain::TournamentResponse,
+        watermarks: &HashMap<i64, NaiveDateTime>,
+    ) -> bool {
+        let Some(watermark) = watermarks.get(&tournament.venue_id()) else {
+            return false;
+        };
+
+        match Self::parse_cuescore_date(&tournament.starttime) {
+            Some(start) => start < *watermark,
+            None => false,
+        }
     }
 
     fn is_cached(&self, tournament_id: i64) -> Result<bool> {
-        Ok(self.cache.load_raw(&tournament_id.to_string())?.is_some())
+        match &self.source {
+            ActiveSource::CueScore { .. } => Ok(self.cache.load_raw(&tournament_id.to_string())?.is_some()),
+            // Challonge fetches go straight through `TournamentSource`, with
+            // no raw-JSON cache tier yet — every fetch counts as uncached.
+            ActiveSource::Challonge(_) => Ok(false),
+        }
+    }
+
+    /// Whether a cached tournament should be force-refreshed rather than
+    /// reused as-is: true when we've never recorded a per-tournament sync
+    /// for it, it's still ongoing (no `stoptime` yet), or it finished after
+    /// the last time we synced it. Long-finished tournaments with nothing
+    /// new since their last sync are left alone.
+    fn needs_refresh(&self, tournament_id: i64) -> Result<bool> {
+        let last_sync = match database::tournament_sync::get_last_sync(&self.pool, tournament_id)? {
+            Some(ts) => ts,
+            None => return Ok(true),
+        };
+
+        let end_date = self
+            .cache
+            .load_raw(&tournament_id.to_string())?
+            .and_then(|v| v.get("stoptime").cloned())
+            .and_then(|v| v.as_str().map(str::to_string))
+            .and_then(|s| Self::parse_cuescore_date(&s));
+
+        match end_date {
+            None => Ok(true),
+            Some(end) => Ok(end >= last_sync),
+        }
+    }
+
+    fn parse_cuescore_date(date_str: &str) -> Option<NaiveDateTime> {
+        use chrono::DateTime;
+
+        if let Ok(dt) = DateTime::parse_from_rfc3339(date_str) {
+            return Some(dt.naive_utc());
+        }
+
+        NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S%.f"))
+            .ok()
     }
 
     async fn fetch_single_tournament(&mut self, tournament_id: i64) -> Result<Option<crate::domain::TournamentResponse>> {
-        self.api_client.fetch_and_cache_tournament(tournament_id, &self.cache).await
+        match &mut self.source {
+            ActiveSource::CueScore { client, .. } => {
+                client.fetch_and_cache_tournament(tournament_id, &self.cache).await
+            }
+            ActiveSource::Challonge(client) => match client.fetch_tournament(tournament_id).await {
+                Ok(tournament) => Ok(Some(tournament)),
+                Err(e) => {
+                    log::error!("Failed to fetch Challonge tournament {}: {:?}", tournament_id, e);
+                    Ok(None)
+                }
+            },
+        }
     }
 
     fn update_progress(&self, progress: &mut FetchProgress, was_cached: bool) {
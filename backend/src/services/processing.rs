@@ -4,9 +4,9 @@ use std::collections::HashMap;
 use chrono::{Utc, Duration, NaiveDateTime};
 
 use crate::cache::Cache;
-use crate::config::settings::AppConfig;
+use crate::config::settings::{AppConfig, RatingPeriod};
 use crate::database::{self, DbConn};
-use crate::domain::{self, ExpandedGame};
+use crate::domain::{self, Discipline, ExpandedGame};
 use crate::rating;
 
 pub struct ProcessingService {
@@ -25,9 +25,19 @@ impl ProcessingService {
     pub fn run(&self) -> Result<()> {
         let db_path = std::env::var("DATABASE_PATH")
             .unwrap_or_else(|_| "warsaw_pool_ranking.db".to_string());
-        let temp_db_path = format!("{}.tmp", db_path);
 
-        info!("=== Starting Data Processing (Atomic) ===\n");
+        if Self::has_existing_data(&db_path)? {
+            info!("=== Starting Data Processing (Incremental) ===\n");
+            info!("Target DB: {} (in place)", db_path);
+
+            self.process_incremental(&db_path)?;
+
+            info!("=== Processing Complete ===");
+            return Ok(());
+        }
+
+        info!("=== Starting Data Processing (Full Rebuild, Atomic) ===\n");
+        let temp_db_path = format!("{}.tmp", db_path);
         info!("Target DB: {}, Temp DB: {}", db_path, temp_db_path);
 
         // Clean up previous temp file if exists
@@ -46,48 +56,232 @@ impl ProcessingService {
         Ok(())
     }
 
+    /// Incremental only makes sense once a prior run has actually populated
+    /// `db_path` — an empty or missing DB falls back to the full rebuild
+    /// path so the first run always has a schema to work with.
+    fn has_existing_data(db_path: &str) -> Result<bool> {
+        if !std::path::Path::new(db_path).exists() {
+            return Ok(false);
+        }
+
+        let pool = database::create_pool(db_path)?;
+        let mut conn = database::get_connection(&pool)?;
+        Ok(!database::tournaments::list_all_cuescore_ids(&mut conn)?.is_empty())
+    }
+
+    /// Diffs the cached tournaments against what's already in `db_path` (by
+    /// cuescore id), ingests only the new ones, then refits ratings over the
+    /// complete history — warm-started from the previous run's log-gammas so
+    /// the MM solver converges in a handful of iterations rather than from
+    /// gamma=1. Unlike `process_to_db`, this writes directly to `db_path`:
+    /// there's no full rebuild to swap in atomically.
+    fn process_incremental(&self, db_path: &str) -> Result<()> {
+        let pool = database::create_pool(db_path)?;
+        let mut conn = database::get_connection(&pool)?;
+
+        // An existing database may predate a schema change that shipped
+        // since its last run; bring it up to date before touching it.
+        database::migrations::migrate(&mut conn)?;
+
+        let tournaments = self.load_tournaments_from_cache()?;
+        info!("  → Loaded {} tournaments from cache\n", tournaments.len());
+
+        let existing_ids: std::collections::HashSet<i64> =
+            database::tournaments::list_all_cuescore_ids(&mut conn)?
+                .into_iter()
+                .collect();
+        let new_tournaments: Vec<&crate::domain::TournamentResponse> = tournaments
+            .iter()
+            .filter(|t| !existing_ids.contains(&t.id))
+            .collect();
+        info!(
+            "  → {} new tournaments to ingest ({} already in the database)",
+            new_tournaments.len(),
+            existing_ids.len()
+        );
+
+        let mut ingested_games = 0;
+        for (idx, tournament) in new_tournaments.iter().enumerate() {
+            if (idx + 1) % 100 == 0 || idx + 1 == new_tournaments.len() {
+                info!("  Ingesting new tournament {}/{}", idx + 1, new_tournaments.len());
+            }
+            ingested_games += self.insert_one_tournament(&mut conn, tournament)?;
+        }
+        info!("  → Inserted {} new games\n", ingested_games);
+
+        self.rebuild_advantage_network(&mut conn)?;
+
+        // The MM solver still needs the complete history to fit against, so
+        // re-expand every cached tournament (old and new) in memory — this
+        // is pure computation, no DB writes, unlike the ingest loop above.
+        let (all_expanded_games, games_by_discipline) = self.expand_all_games(&tournaments)?;
+        info!("  → {} games total across the full history", all_expanded_games.len());
+
+        for period in &self.config.rating.periods {
+            info!("  Calculating ratings for period: {}", period.name);
+
+            if self.config.rating.combine_disciplines {
+                let filtered_games = Self::filter_by_period(&all_expanded_games, period);
+                info!("    → {} games for period {}", filtered_games.len(), period.name);
+
+                let warm_start = self.warm_start_for(&mut conn, &period.name)?;
+                let ratings = self.calculate_player_ratings(&filtered_games, &period.name, &warm_start)?;
+                info!("    → Calculated ratings for {} players for period {}", ratings.len(), period.name);
+
+                self.save_ratings_to_db(&mut conn, &ratings, &period.name)?;
+            }
+
+            for discipline in &self.config.rating.disciplines {
+                let rating_type = format!("{}_{}", discipline.as_str(), period.name);
+                let discipline_games = games_by_discipline.get(discipline).map(Vec::as_slice).unwrap_or(&[]);
+                let filtered_games = Self::filter_by_period(discipline_games, period);
+                info!("    → {} games for {}", filtered_games.len(), rating_type);
+
+                let warm_start = self.warm_start_for(&mut conn, &rating_type)?;
+                let ratings = self.calculate_player_ratings(&filtered_games, &rating_type, &warm_start)?;
+                info!("    → Calculated ratings for {} players for {}", ratings.len(), rating_type);
+
+                self.save_ratings_to_db(&mut conn, &ratings, &rating_type)?;
+            }
+
+            info!("    → Saved ratings for period {} to database\n", period.name);
+        }
+
+        Ok(())
+    }
+
+    /// Previous run's ratings for `rating_type`, converted back to
+    /// log-gammas to seed the MM solver with. Empty (cold start) the first
+    /// time a `rating_type` is computed.
+    fn warm_start_for(&self, conn: &mut DbConn, rating_type: &str) -> Result<HashMap<rating::PlayerId, f64>> {
+        let prior_ratings = database::ratings::list_latest_ratings_by_cuescore_id(conn, rating_type)?;
+
+        Ok(prior_ratings
+            .into_iter()
+            .map(|(cuescore_id, rating_value)| {
+                let log_gamma = rating::bradley_terry::log_gamma_from_rating(rating_value, &self.config.rating);
+                (cuescore_id as rating::PlayerId, log_gamma)
+            })
+            .collect())
+    }
+
+    /// Ingests a single tournament that isn't in the DB yet: upserts it and
+    /// its games. Returns the number of games inserted (0 for a
+    /// doubles/team tournament, which is skipped).
+    fn insert_one_tournament(
+        &self,
+        conn: &mut DbConn,
+        tournament: &crate::domain::TournamentResponse,
+    ) -> Result<usize> {
+        if self.is_doubles_tournament(&tournament.name) {
+            return Ok(0);
+        }
+
+        let player_info_map = self.extract_player_info(tournament);
+        let tournament_db = self.insert_tournament_to_db(conn, tournament)?;
+        let games = self.expand_and_filter_games(tournament)?;
+
+        self.insert_games_to_db(conn, &games, tournament_db.id, &player_info_map, &tournament_db.discipline)?;
+
+        Ok(games.len())
+    }
+
+    /// Expands every cached tournament to games without touching the
+    /// database — used by the incremental path to refit ratings over the
+    /// complete history even when only a handful of tournaments are new.
+    /// Mirrors the doubles/team filtering `process_tournaments` applies on
+    /// the DB-write path, just without the DB inserts.
+    fn expand_all_games(
+        &self,
+        tournaments: &[crate::domain::TournamentResponse],
+    ) -> Result<(Vec<ExpandedGame>, HashMap<Discipline, Vec<ExpandedGame>>)> {
+        let mut all_games = Vec::new();
+        let mut games_by_discipline: HashMap<Discipline, Vec<ExpandedGame>> = HashMap::new();
+
+        for tournament in tournaments {
+            if self.is_doubles_tournament(&tournament.name) {
+                continue;
+            }
+
+            let games = self.expand_and_filter_games(tournament)?;
+            games_by_discipline
+                .entry(tournament.discipline())
+                .or_default()
+                .extend(games.iter().cloned());
+            all_games.extend(games);
+        }
+
+        self.apply_time_decay_weights(&mut all_games);
+        for games in games_by_discipline.values_mut() {
+            self.apply_time_decay_weights(games);
+        }
+
+        Ok((all_games, games_by_discipline))
+    }
+
     fn process_to_db(&self, db_path: &str) -> Result<()> {
         let pool = database::create_pool(db_path)?;
         let mut conn = database::get_connection(&pool)?;
 
-        // Step 1: Reset database (PoC - no migrations)
-        database::setup::reset_database(&mut conn)?;
-        info!("  → Database schema reset\n");
+        // Step 1: Bring the schema up to date without wiping existing data.
+        database::migrations::migrate(&mut conn)?;
+        info!("  → Database schema migrated\n");
 
         // Step 2: Load cached tournaments
         let tournaments = self.load_tournaments_from_cache()?;
         info!("  → Loaded {} tournaments from cache\n", tournaments.len());
 
         // Step 3: Insert tournaments and expand to games (all games, before filtering for periods)
-        let all_expanded_games = self.process_tournaments(&mut conn, &tournaments)?;
+        let (all_expanded_games, games_by_discipline) = self.process_tournaments(&mut conn, &tournaments)?;
         info!("  → Expanded to {} individual games (total)", all_expanded_games.len());
 
-        // Step 4: Calculate and save ratings for each period
+        self.rebuild_advantage_network(&mut conn)?;
+
+        // Step 4: Calculate and save ratings for each period, and (per
+        // RatingSettings) for each (discipline, period) pair, so a strong
+        // 9-ball player and a strong 8-ball player are never compared on
+        // the same table.
         for period in &self.config.rating.periods {
             info!("  Calculating ratings for period: {}", period.name);
 
-            let filtered_games = if let Some(years) = period.years {
-                let cutoff_date = Utc::now().naive_utc() - Duration::days((years * 365) as i64);
-                all_expanded_games.iter()
-                    .filter(|game| game.date >= cutoff_date)
-                    .cloned()
-                    .collect::<Vec<ExpandedGame>>()
-            } else {
-                all_expanded_games.clone()
-            };
+            if self.config.rating.combine_disciplines {
+                let filtered_games = Self::filter_by_period(&all_expanded_games, period);
+                info!("    → {} games for period {}", filtered_games.len(), period.name);
+
+                let ratings = self.calculate_player_ratings(&filtered_games, &period.name, &HashMap::new())?;
+                info!("    → Calculated ratings for {} players for period {}", ratings.len(), period.name);
 
-            info!("    → {} games for period {}", filtered_games.len(), period.name);
+                self.save_ratings_to_db(&mut conn, &ratings, &period.name)?;
+            }
 
-            let ratings = self.calculate_player_ratings(&filtered_games, &period.name)?;
-            info!("    → Calculated ratings for {} players for period {}", ratings.len(), period.name);
+            for discipline in &self.config.rating.disciplines {
+                let rating_type = format!("{}_{}", discipline.as_str(), period.name);
+                let discipline_games = games_by_discipline.get(discipline).map(Vec::as_slice).unwrap_or(&[]);
+                let filtered_games = Self::filter_by_period(discipline_games, period);
+                info!("    → {} games for {}", filtered_games.len(), rating_type);
+
+                let ratings = self.calculate_player_ratings(&filtered_games, &rating_type, &HashMap::new())?;
+                info!("    → Calculated ratings for {} players for {}", ratings.len(), rating_type);
+
+                self.save_ratings_to_db(&mut conn, &ratings, &rating_type)?;
+            }
 
-            self.save_ratings_to_db(&mut conn, &ratings, &period.name)?;
             info!("    → Saved ratings for period {} to database\n", period.name);
         }
 
         Ok(())
     }
 
+    fn filter_by_period(games: &[ExpandedGame], period: &RatingPeriod) -> Vec<ExpandedGame> {
+        match period.years {
+            Some(years) => {
+                let cutoff_date = Utc::now().naive_utc() - Duration::days((years * 365) as i64);
+                games.iter().filter(|game| game.date >= cutoff_date).cloned().collect()
+            }
+            None => games.to_vec(),
+        }
+    }
+
     fn load_tournaments_from_cache(&self) -> Result<Vec<crate::domain::TournamentResponse>> {
         self.cache
             .load_parsed("tournaments")?
@@ -98,8 +292,9 @@ impl ProcessingService {
         &self,
         conn: &mut DbConn,
         tournaments: &[crate::domain::TournamentResponse],
-    ) -> Result<Vec<ExpandedGame>> {
+    ) -> Result<(Vec<ExpandedGame>, HashMap<Discipline, Vec<ExpandedGame>>)> {
         let mut all_games = Vec::new();
+        let mut games_by_discipline: HashMap<Discipline, Vec<ExpandedGame>> = HashMap::new();
         let mut skipped_count = 0;
 
         for (idx, tournament) in tournaments.iter().enumerate() {
@@ -115,25 +310,27 @@ impl ProcessingService {
             let player_info_map = self.extract_player_info(tournament);
 
             let tournament_db = self.insert_tournament_to_db(conn, tournament)?;
-            let mut games = self.expand_tournament_games(tournament)?;
+            let games = self.expand_and_filter_games(tournament)?;
+
+            self.insert_games_to_db(conn, &games, tournament_db.id, &player_info_map, &tournament_db.discipline)?;
 
-            games.retain(|g| {
-                let w_name = player_info_map.get(&g.winner_id).map(|p| p.name.as_str()).unwrap_or("");
-                let l_name = player_info_map.get(&g.loser_id).map(|p| p.name.as_str()).unwrap_or("");
-                !self.is_team_player(w_name) && !self.is_team_player(l_name)
-            });
+            let discipline = Discipline::from_slug(&tournament_db.discipline).unwrap_or(Discipline::Other);
+            games_by_discipline.entry(discipline).or_default().extend(games.iter().cloned());
 
-            self.insert_games_to_db(conn, &games, tournament_db.id, &player_info_map)?;
-            all_games.append(&mut games);
+            all_games.extend(games);
         }
 
         if skipped_count > 0 {
             info!("  Skipped {} doubles/team tournaments", skipped_count);
         }
 
-        // Apply time decay only once, on the full set of games, before filtering by period
+        // Apply time decay only once per bucket, before filtering by period
         self.apply_time_decay_weights(&mut all_games);
-        Ok(all_games)
+        for games in games_by_discipline.values_mut() {
+            self.apply_time_decay_weights(games);
+        }
+
+        Ok((all_games, games_by_discipline))
     }
 
     fn is_doubles_tournament(&self, name: &str) -> bool {
@@ -166,6 +363,7 @@ impl ProcessingService {
             &tournament.venue_name(),
             start_date,
             end_date,
+            tournament.discipline().as_str(),
         )
     }
 
@@ -204,6 +402,26 @@ impl ProcessingService {
         domain::games_expansion::expand_tournament_to_games(tournament)
     }
 
+    /// Expands a tournament to games and drops any involving a team/doubles
+    /// player name, same as the inline filtering `process_tournaments` used
+    /// to do — factored out so the incremental path can reuse it without a
+    /// DB write.
+    fn expand_and_filter_games(
+        &self,
+        tournament: &crate::domain::TournamentResponse,
+    ) -> Result<Vec<ExpandedGame>> {
+        let player_info_map = self.extract_player_info(tournament);
+        let mut games = self.expand_tournament_games(tournament)?;
+
+        games.retain(|g| {
+            let w_name = player_info_map.get(&g.winner_id).map(|p| p.name.as_str()).unwrap_or("");
+            let l_name = player_info_map.get(&g.loser_id).map(|p| p.name.as_str()).unwrap_or("");
+            !self.is_team_player(w_name) && !self.is_team_player(l_name)
+        });
+
+        Ok(games)
+    }
+
     fn extract_player_info(
         &self,
         tournament: &crate::domain::TournamentResponse,
@@ -230,6 +448,7 @@ impl ProcessingService {
         games: &[ExpandedGame],
         tournament_db_id: i32,
         player_info_map: &HashMap<i64, domain::PlayerInfo>,
+        discipline: &str,
     ) -> Result<()> {
         for game in games {
             let first_player_info = player_info_map.get(&game.winner_id)
@@ -249,6 +468,7 @@ impl ProcessingService {
                 0,
                 game.date,
                 game.weight,
+                discipline,
             )?;
         }
 
@@ -275,27 +495,23 @@ impl ProcessingService {
         &self,
         games: &[ExpandedGame],
         rating_type: &str,
+        warm_start: &HashMap<rating::PlayerId, f64>,
     ) -> Result<Vec<rating::PlayerRating>> {
-        let game_results = self.convert_to_game_results(games);
-        let mut ratings = rating::calculate_ratings(&game_results, &self.config.rating);
+        let mut ratings = rating::calculate_ratings(games, &self.config.rating, warm_start);
         for r in &mut ratings {
             r.rating_type = rating_type.to_string();
         }
         Ok(ratings)
     }
 
-    fn convert_to_game_results(
-        &self,
-        games: &[ExpandedGame],
-    ) -> Vec<rating::GameResult> {
-        games
-            .iter()
-            .map(|g| rating::GameResult {
-                winner_id: g.winner_id as i32,
-                loser_id: g.loser_id as i32,
-                weight: g.weight,
-            })
-            .collect()
+    /// Recomputes the persisted pairwise advantage network from the
+    /// complete game history, so head-to-head requests can load it straight
+    /// from `network` instead of scanning every game each time.
+    fn rebuild_advantage_network(&self, conn: &mut DbConn) -> Result<()> {
+        let all_games = database::games::list_all(conn)?;
+        let edge_count = database::network::rebuild_from_games(conn, &all_games)?;
+        info!("  → Rebuilt advantage network: {} player pairs\n", edge_count);
+        Ok(())
     }
 
     fn save_ratings_to_db(
@@ -321,11 +537,24 @@ impl ProcessingService {
                 player_rating.rating,
                 player_rating.games_played,
                 player_rating.confidence_level.as_str(),
+                player_rating.rating_deviation,
+                player_rating.volatility,
                 calculated_at,
             ) {
                 error!("Failed to insert rating for player {}: {:?}", player.id, e);
                 return Err(e.into());
             }
+
+            // Snapshot this recompute into the history table, keyed by the
+            // same timestamp, so the front end can chart rating over time.
+            database::rating_history::insert_snapshot(
+                conn,
+                player.id,
+                rating_type,
+                calculated_at,
+                player_rating.rating,
+                player_rating.games_played,
+            )?;
         }
 
         Ok(())